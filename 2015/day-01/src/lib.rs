@@ -0,0 +1,53 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    // +1 for each ( and -1 for each )
+    type Parsed<'a> = Vec<isize>;
+
+    fn parse(input: &str) -> Result<Vec<isize>, ParseError> {
+        aoc_parse::cells(input, input.trim(), |ch| match ch {
+            '(' => Some(1),
+            ')' => Some(-1),
+            _ => None,
+        })
+    }
+
+    fn part1(steps: &Self::Parsed<'_>) -> impl Display {
+        steps.iter().sum::<isize>()
+    }
+
+    // the position of the step that first reaches the basement
+    fn part2(steps: &Self::Parsed<'_>) -> impl Display {
+        let mut floor = 0;
+        for (i, step) in steps.iter().enumerate() {
+            floor += step;
+            if floor < 0 {
+                return i + 1;
+            }
+        }
+        0
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_solution::part1;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            part1::<Day>("(x)").unwrap_err().to_string(),
+            "line 1, column 2: unexpected 'x'"
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Present {
+    length: u32,
+    width: u32,
+    height: u32,
+}
+
+impl Present {
+    fn new(length: u32, width: u32, height: u32) -> Self {
+        Self {
+            length,
+            width,
+            height,
+        }
+    }
+
+    fn area(&self) -> u32 {
+        2 * self.length * self.width + 2 * self.width * self.height + 2 * self.height * self.length
+    }
+
+    fn paper(&self) -> u32 {
+        self.area()
+            + ((self.length * self.width)
+                .min(self.width * self.height)
+                .min(self.height * self.length))
+    }
+
+    fn ribbon(&self) -> u32 {
+        let around = if self.length >= self.width && self.length >= self.height {
+            self.width + self.width + self.height + self.height
+        } else if self.width >= self.length && self.width >= self.height {
+            self.height + self.height + self.length + self.length
+        } else {
+            assert!(self.height >= self.width && self.height >= self.length);
+            self.width + self.width + self.length + self.length
+        };
+        around + self.height * self.width * self.length
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Present>;
+
+    fn parse(input: &str) -> Result<Vec<Present>, ParseError> {
+        // 19x19x18
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (length, rest) = aoc_parse::split_once(input, line, "x")?;
+                let (width, height) = aoc_parse::split_once(input, rest, "x")?;
+                Ok(Present::new(
+                    aoc_parse::parse(input, length, "a length")?,
+                    aoc_parse::parse(input, width, "a width")?,
+                    aoc_parse::parse(input, height, "a height")?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(presents: &Self::Parsed<'_>) -> impl Display {
+        presents.iter().map(|p| p.paper()).sum::<u32>()
+    }
+
+    fn part2(presents: &Self::Parsed<'_>) -> impl Display {
+        presents.iter().map(|p| p.ribbon()).sum::<u32>()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,51 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+type Pos = (isize, isize);
+
+// every house visited by following the moves from (0, 0), including it
+fn visit(moves: impl Iterator<Item = Pos>, houses: &mut HashSet<Pos>) {
+    let mut pos = (0, 0);
+    houses.insert(pos);
+    for (dx, dy) in moves {
+        pos = (pos.0 + dx, pos.1 + dy);
+        houses.insert(pos);
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Pos>;
+
+    fn parse(input: &str) -> Result<Vec<Pos>, ParseError> {
+        aoc_parse::cells(input, input.trim(), |ch| match ch {
+            '>' => Some((1, 0)),
+            '<' => Some((-1, 0)),
+            '^' => Some((0, -1)),
+            'v' => Some((0, 1)),
+            _ => None,
+        })
+    }
+
+    fn part1(moves: &Self::Parsed<'_>) -> impl Display {
+        let mut houses = HashSet::new();
+        visit(moves.iter().copied(), &mut houses);
+        houses.len()
+    }
+
+    // santa and robo-santa take turns with the moves
+    fn part2(moves: &Self::Parsed<'_>) -> impl Display {
+        let mut houses = HashSet::new();
+        visit(moves.iter().copied().step_by(2), &mut houses);
+        visit(moves.iter().copied().skip(1).step_by(2), &mut houses);
+        houses.len()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
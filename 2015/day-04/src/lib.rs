@@ -0,0 +1,31 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+use crate::mining::mine;
+
+mod mining;
+
+pub struct Day;
+
+impl Solution for Day {
+    // the secret key
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<&str, ParseError> {
+        Ok(input.trim())
+    }
+
+    fn part1(key: &Self::Parsed<'_>) -> impl Display {
+        mine(key, 5).expect("5 zeros to fit in a hash")
+    }
+
+    fn part2(key: &Self::Parsed<'_>) -> impl Display {
+        mine(key, 6).expect("6 zeros to fit in a hash")
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,100 @@
+use md5::Context;
+use rayon::prelude::*;
+
+// how many numbers to try in parallel before checking for a hit, small enough
+// that not much is wasted past the answer
+const BATCH: u64 = 1 << 16;
+
+// hex digits in an MD5 hash
+const DIGITS: usize = 32;
+
+// the lowest number that, after the key, hashes to at least `zeros` leading
+// zero hex digits
+pub fn mine(key: &str, zeros: usize) -> Result<u64, String> {
+    if zeros > DIGITS {
+        return Err(format!(
+            "a {DIGITS}-digit hash can't start with {zeros} zeros"
+        ));
+    }
+
+    // every candidate starts with the key, so that's only hashed once
+    let mut prefix = Context::new();
+    prefix.consume(key);
+
+    let found = (0..)
+        .step_by(BATCH as usize)
+        .find_map(|start| {
+            (start..start + BATCH).into_par_iter().find_first(|num| {
+                let mut context = prefix.clone();
+                let mut digits = [0; 20];
+                context.consume(decimal(*num, &mut digits));
+                has_zeros(&context.compute().0, zeros)
+            })
+        })
+        .expect("some number to give the hash");
+    Ok(found)
+}
+
+// the number's digits, written into the end of buf
+fn decimal(mut num: u64, buf: &mut [u8; 20]) -> &[u8] {
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (num % 10) as u8;
+        num /= 10;
+        if num == 0 {
+            return &buf[start..];
+        }
+    }
+}
+
+// checking the bytes, two hex digits to each, rather than formatting them
+fn has_zeros(digest: &[u8], zeros: usize) -> bool {
+    digest[..zeros / 2].iter().all(|byte| *byte == 0)
+        && (zeros.is_multiple_of(2) || digest[zeros / 2] >> 4 == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits() {
+        let mut buf = [0; 20];
+        assert_eq!(decimal(0, &mut buf), b"0");
+        assert_eq!(decimal(609043, &mut buf), b"609043");
+        assert_eq!(decimal(u64::MAX, &mut buf), u64::MAX.to_string().as_bytes());
+    }
+
+    #[test]
+    fn zeros() {
+        let digest = [0x00, 0x00, 0x0f, 0xff];
+        assert!(has_zeros(&digest, 0));
+        assert!(has_zeros(&digest, 4));
+        assert!(has_zeros(&digest, 5));
+        assert!(!has_zeros(&digest, 6));
+        assert!(has_zeros(&[0; 16], 32));
+    }
+
+    #[test]
+    fn too_many_zeros() {
+        assert_eq!(
+            mine("abcdef", 33),
+            Err("a 32-digit hash can't start with 33 zeros".to_string())
+        );
+    }
+
+    #[test]
+    fn same_as_formatting() {
+        // cheap enough to check against the obvious way
+        for zeros in 1..=3 {
+            let expected = (0..)
+                .find(|num| {
+                    let hash = format!("{:x}", md5::compute(format!("abcdef{num}")));
+                    hash.starts_with(&"0".repeat(zeros))
+                })
+                .unwrap();
+            assert_eq!(mine("abcdef", zeros), Ok(expected));
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+fn is_nice(word: &str) -> bool {
+    if word.len() < 3 {
+        return false;
+    }
+
+    let mut vowels = 0;
+    let mut double = 0;
+    let chars = word.chars().collect::<Vec<_>>(); // not the most optimal, but Rust definitely complicates things here
+    for i in 0..chars.len() {
+        let ch = chars[i];
+        if let Some(ch2) = chars.get(i + 1) {
+            if (ch == 'a' && ch2 == &'b')
+                || (ch == 'c' && ch2 == &'d')
+                || (ch == 'p' && ch2 == &'q')
+                || (ch == 'x' && ch2 == &'y')
+            {
+                // disallowed pair
+                return false;
+            }
+
+            if &ch == ch2 {
+                double += 1;
+            }
+        }
+
+        if ch == 'a' || ch == 'e' || ch == 'i' || ch == 'o' || ch == 'u' {
+            vowels += 1;
+        }
+    }
+
+    vowels >= 3 && double > 0
+}
+
+// part 2's better rules
+fn is_really_nice(word: &str) -> bool {
+    if word.len() < 3 {
+        return false;
+    }
+
+    let mut matching_pair = false;
+    let mut repeat_with_sep = false;
+    let chars = word.chars().collect::<Vec<_>>();
+    for i in 0..chars.len() - 1 {
+        let ch = chars[i];
+        let ch2 = chars[i + 1];
+        if !matching_pair {
+            for j in i + 2..chars.len() - 1 {
+                let k1 = chars[j];
+                let k2 = chars[j + 1];
+                if ch == k1 && ch2 == k2 {
+                    matching_pair = true;
+                }
+            }
+        }
+
+        if !repeat_with_sep && i + 2 < chars.len() {
+            let ch3 = chars[i + 2];
+            if ch == ch3 {
+                repeat_with_sep = true;
+            }
+        }
+
+        if repeat_with_sep && matching_pair {
+            return true;
+        }
+    }
+    false
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        input
+            .trim()
+            .lines()
+            .map(
+                |word| match word.find(|ch: char| !ch.is_ascii_lowercase()) {
+                    Some(i) => Err(ParseError::at(input, &word[i..i + 1], "expected a-z")),
+                    None => Ok(word),
+                },
+            )
+            .collect()
+    }
+
+    fn part1(words: &Self::Parsed<'_>) -> impl Display {
+        words.iter().filter(|word| is_nice(word)).count()
+    }
+
+    fn part2(words: &Self::Parsed<'_>) -> impl Display {
+        words.iter().filter(|word| is_really_nice(word)).count()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nice_strings() {
+        assert!(is_nice("ugknbfddgicrmopn"));
+        assert!(is_nice("aaa"));
+        assert!(!is_nice("jchzalrnumimnmhp"));
+        assert!(!is_nice("haegwjzuvuyypxyu"));
+        assert!(!is_nice("dvszwmarrgswjxmb"));
+
+        assert!(is_really_nice("qjhvhtzxzqqjkmpb"));
+        assert!(is_really_nice("xxyxx"));
+        assert!(!is_really_nice("uurcxstgmygtbstg"));
+        assert!(!is_really_nice("ieodomkazucvgmuy"));
+    }
+}
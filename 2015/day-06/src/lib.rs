@@ -0,0 +1,29 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use lights::{parse_input, total, Brightness, Instruction, Switch};
+use std::fmt::Display;
+
+mod lights;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(instructions: &Self::Parsed<'_>) -> impl Display {
+        total::<Switch>(instructions)
+    }
+
+    fn part2(instructions: &Self::Parsed<'_>) -> impl Display {
+        total::<Brightness>(instructions)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,209 @@
+use aoc_parse::ParseError;
+
+/*
+turn off 231,492 through 790,976
+turn on 874,567 through 943,684
+toggle 911,840 through 990,932
+ */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    On,
+    Off,
+    Toggle,
+}
+
+// a rectangle of lights, corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub action: Action,
+    pub from: (usize, usize),
+    pub to: (usize, usize),
+}
+
+fn parse_point(input: &str, point: &str) -> Result<(usize, usize), ParseError> {
+    let (x, y) = aoc_parse::split_once(input, point, ",")?;
+    Ok((
+        aoc_parse::parse(input, x, "a column")?,
+        aoc_parse::parse(input, y, "a row")?,
+    ))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let actions = [
+        ("turn on ", Action::On),
+        ("turn off ", Action::Off),
+        ("toggle ", Action::Toggle),
+    ];
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (action, rest) = actions
+                .iter()
+                .find_map(|&(prefix, action)| Some((action, line.strip_prefix(prefix)?)))
+                .ok_or_else(|| {
+                    ParseError::at(input, line, "expected turn on, turn off or toggle")
+                })?;
+            let (from, to) = aoc_parse::split_once(input, rest, " through ")?;
+            let instruction = Instruction {
+                action,
+                from: parse_point(input, from)?,
+                to: parse_point(input, to)?,
+            };
+            // a reversed rectangle would have no lights in it
+            if instruction.to.0 < instruction.from.0 || instruction.to.1 < instruction.from.1 {
+                let message = format!("expected a corner at or after {from}");
+                return Err(ParseError::at(input, to, message));
+            }
+            Ok(instruction)
+        })
+        .collect()
+}
+
+// what the instructions do to a light, and what a light counts for at the end
+pub trait Semantics {
+    type Light: Copy + Default;
+
+    fn apply(light: Self::Light, action: Action) -> Self::Light;
+
+    fn value(light: Self::Light) -> usize;
+}
+
+// part 1 - lights are just on or off
+pub struct Switch;
+
+impl Semantics for Switch {
+    type Light = bool;
+
+    fn apply(light: bool, action: Action) -> bool {
+        match action {
+            Action::On => true,
+            Action::Off => false,
+            Action::Toggle => !light,
+        }
+    }
+
+    fn value(light: bool) -> usize {
+        light as usize
+    }
+}
+
+// part 2 - the instructions were really about brightness
+pub struct Brightness;
+
+impl Semantics for Brightness {
+    type Light = usize;
+
+    fn apply(light: usize, action: Action) -> usize {
+        match action {
+            Action::On => light + 1,
+            Action::Off => light.saturating_sub(1),
+            Action::Toggle => light + 2,
+        }
+    }
+
+    fn value(light: usize) -> usize {
+        light
+    }
+}
+
+// the sorted edges of every rectangle along one axis - the lights between
+// one edge and the next are always treated alike
+fn edges(instructions: &[Instruction], axis: impl Fn(&(usize, usize)) -> usize) -> Vec<usize> {
+    let mut edges = instructions
+        .iter()
+        .flat_map(|i| [axis(&i.from), axis(&i.to) + 1])
+        .collect::<Vec<_>>();
+    edges.sort();
+    edges.dedup();
+    edges
+}
+
+// the total value of all the lights after following the instructions, with
+// the grid compressed to one light per block between the rectangles' edges
+// so it only depends on how many instructions there are, not how big they are
+pub fn total<S: Semantics>(instructions: &[Instruction]) -> usize {
+    let xs = edges(instructions, |p| p.0);
+    let ys = edges(instructions, |p| p.1);
+    let width = xs.len().saturating_sub(1);
+    let mut blocks = vec![S::Light::default(); width * ys.len().saturating_sub(1)];
+
+    let index = |edges: &[usize], edge| edges.binary_search(&edge).expect("an edge");
+    for instruction in instructions {
+        let (x1, x2) = (
+            index(&xs, instruction.from.0),
+            index(&xs, instruction.to.0 + 1),
+        );
+        let (y1, y2) = (
+            index(&ys, instruction.from.1),
+            index(&ys, instruction.to.1 + 1),
+        );
+        for y in y1..y2 {
+            for light in &mut blocks[y * width + x1..y * width + x2] {
+                *light = S::apply(*light, instruction.action);
+            }
+        }
+    }
+
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, light)| {
+            let (x, y) = (i % width, i / width);
+            S::value(*light) * (xs[x + 1] - xs[x]) * (ys[y + 1] - ys[y])
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compressed() {
+        let instructions = parse_input(
+            "turn on 0,0 through 999,999
+toggle 0,0 through 999,0
+turn off 499,499 through 500,500",
+        )
+        .unwrap();
+        assert_eq!(edges(&instructions, |p| p.0), [0, 499, 501, 1000]);
+        assert_eq!(edges(&instructions, |p| p.1), [0, 1, 499, 501, 1000]);
+        assert_eq!(total::<Switch>(&instructions), 1_000_000 - 1000 - 4);
+        assert_eq!(total::<Brightness>(&instructions), 1_000_000 + 2000 - 4);
+    }
+
+    #[test]
+    fn huge() {
+        // far too many lights to have one each
+        let instructions = parse_input(
+            "turn on 0,0 through 999999999,999999999
+turn off 1,1 through 999999998,999999998
+toggle 0,0 through 0,0",
+        )
+        .unwrap();
+        let edge = 1_000_000_000;
+        assert_eq!(total::<Switch>(&instructions), 4 * (edge - 1) - 1);
+        assert_eq!(total::<Brightness>(&instructions), 4 * (edge - 1) + 2);
+    }
+
+    #[test]
+    fn invalid() {
+        let error = parse_input("turn on 0,0 through 9,x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 23: expected a row, found 'x'"
+        );
+        let error = parse_input("turn on 5,0 through 2,3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 21: expected a corner at or after 5,0"
+        );
+    }
+
+    #[test]
+    fn nothing() {
+        assert_eq!(total::<Switch>(&[]), 0);
+    }
+}
@@ -0,0 +1,40 @@
+use y2015_day_07::circuit::Circuit;
+
+const USAGE: &str = "usage: wires [--set <wire>=<signal>]...";
+
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{message}");
+    std::process::exit(1);
+}
+
+// the signal on every wire, after setting any given wires first
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let mut overrides = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--set" => {
+                let value = args.next().unwrap_or_else(|| fail(USAGE));
+                let (wire, signal) = value.split_once('=').unwrap_or_else(|| fail(USAGE));
+                if signal.parse::<u16>().is_err() {
+                    fail(format!("signal for {wire} isn't a 16 bit number: {signal}"));
+                }
+                overrides.push((wire, signal));
+            }
+            _ => fail(USAGE),
+        }
+    }
+
+    let input = &aoc_input::load_or_exit(2015, 7);
+    let mut circuit = aoc_parse::or_exit(Circuit::parse(input), input);
+    for (wire, signal) in overrides {
+        circuit.set(wire, signal);
+    }
+    let values = circuit.evaluate().unwrap_or_else(|error| fail(error));
+    let mut wires = values.into_iter().collect::<Vec<_>>();
+    wires.sort();
+    for (wire, value) in wires {
+        println!("{wire}: {value}");
+    }
+}
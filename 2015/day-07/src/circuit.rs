@@ -0,0 +1,306 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+
+use aoc_parse::{Context, Cursor, ParseError};
+
+/*
+lx -> a
+fo RSHIFT 3 -> fq
+c LSHIFT 1 -> t
+cj OR cp -> cq
+1 AND cx -> cy
+NOT p -> q
+ */
+// the gate driving a wire, where each input is another wire or a signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expr<'a> {
+    Value(&'a str),
+    LShift(&'a str, u16),
+    RShift(&'a str, u16),
+    Or(&'a str, &'a str),
+    And(&'a str, &'a str),
+    Not(&'a str),
+}
+
+impl<'a> Expr<'a> {
+    // the wires this gate reads, as opposed to the signals
+    fn wires(&self) -> impl Iterator<Item = &'a str> {
+        let inputs = match *self {
+            Expr::Value(a) | Expr::LShift(a, _) | Expr::RShift(a, _) | Expr::Not(a) => [a, ""],
+            Expr::Or(a, b) | Expr::And(a, b) => [a, b],
+        };
+        inputs
+            .into_iter()
+            .filter(|input| !input.is_empty() && input.parse::<u16>().is_err())
+    }
+
+    // with every wire it reads already worked out
+    fn evaluate(&self, values: &HashMap<&str, u16>) -> u16 {
+        let value = |input: &str| input.parse().unwrap_or_else(|_| values[input]);
+        match *self {
+            Expr::Value(a) => value(a),
+            Expr::Not(a) => !value(a),
+            Expr::Or(a, b) => value(a) | value(b),
+            Expr::And(a, b) => value(a) & value(b),
+            // shifting everything out leaves nothing, rather than overflowing
+            Expr::LShift(a, by) => value(a).checked_shl(by.into()).unwrap_or(0),
+            Expr::RShift(a, by) => value(a).checked_shr(by.into()).unwrap_or(0),
+        }
+    }
+}
+
+fn expr<'a>(cursor: &mut Cursor<'a>) -> Result<Expr<'a>, ParseError> {
+    if cursor.optional("NOT ") {
+        return Ok(Expr::Not(cursor.word()?));
+    }
+    let a = cursor.word()?;
+    Ok(if cursor.optional(" AND ") {
+        Expr::And(a, cursor.word()?)
+    } else if cursor.optional(" OR ") {
+        Expr::Or(a, cursor.word()?)
+    } else if cursor.optional(" RSHIFT ") {
+        Expr::RShift(a, cursor.int()?)
+    } else if cursor.optional(" LSHIFT ") {
+        Expr::LShift(a, cursor.int()?)
+    } else {
+        Expr::Value(a)
+    })
+}
+
+// what stops a circuit that parsed from being evaluated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError<'a> {
+    Undefined { wire: &'a str, used_by: &'a str },
+    // the wires around the loop, each needing the next, ending back at the first
+    Cycle(Vec<&'a str>),
+}
+
+impl CircuitError<'_> {
+    // at the wire it's about, for a circuit straight from the input
+    pub fn at(&self, input: &str) -> ParseError {
+        let wire = match self {
+            CircuitError::Undefined { wire, .. } => wire,
+            CircuitError::Cycle(wires) => wires[0],
+        };
+        ParseError::at(input, wire, self.to_string())
+    }
+}
+
+impl Display for CircuitError<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitError::Undefined { wire, used_by } => {
+                write!(
+                    f,
+                    "wire {wire} is used by {used_by} but never given a signal"
+                )
+            }
+            CircuitError::Cycle(wires) => {
+                write!(f, "wires depend on each other: {}", wires.join(" <- "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for CircuitError<'_> {}
+
+#[derive(Clone)]
+pub struct Circuit<'a> {
+    gates: HashMap<&'a str, Expr<'a>>,
+}
+
+impl<'a> Circuit<'a> {
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let wires: Vec<_> = aoc_parse::lines(input, input, |cursor| {
+            let gate = expr(cursor)?;
+            cursor.tag(" -> ")?;
+            Ok((cursor.word()?, gate))
+        })
+        .context("a gate")?;
+        let mut gates = HashMap::new();
+        for (wire, gate) in wires {
+            if gates.insert(wire, gate).is_some() {
+                return Err(ParseError::at(
+                    input,
+                    wire,
+                    format!("wire {wire} has more than one gate"),
+                ));
+            }
+        }
+        Ok(Circuit { gates })
+    }
+
+    // replaces whatever drives the wire with a fixed signal
+    pub fn set(&mut self, wire: &'a str, signal: &'a str) {
+        self.gates.insert(wire, Expr::Value(signal));
+    }
+
+    // the wires with a gate driving them
+    pub fn wires(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.gates.keys().copied()
+    }
+
+    // every wire, each after all the wires it needs
+    fn order(&self) -> Result<Vec<&'a str>, CircuitError<'a>> {
+        let mut wires = self.gates.keys().copied().collect::<Vec<_>>();
+        wires.sort();
+
+        let mut waiting_on = HashMap::new();
+        let mut needed_by: HashMap<&str, Vec<&str>> = HashMap::new();
+        for &wire in &wires {
+            for input in self.gates[wire].wires() {
+                if !self.gates.contains_key(input) {
+                    return Err(CircuitError::Undefined {
+                        wire: input,
+                        used_by: wire,
+                    });
+                }
+                *waiting_on.entry(wire).or_insert(0) += 1;
+                needed_by.entry(input).or_default().push(wire);
+            }
+        }
+
+        // Kahn's algorithm - a wire is ready once everything it needs is
+        let mut ready = wires
+            .iter()
+            .copied()
+            .filter(|wire| !waiting_on.contains_key(wire))
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(wires.len());
+        while let Some(wire) = ready.pop_front() {
+            order.push(wire);
+            for &next in needed_by.get(wire).into_iter().flatten() {
+                let waiting = waiting_on.get_mut(next).expect("a wire with inputs");
+                *waiting -= 1;
+                if *waiting == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+
+        if order.len() < wires.len() {
+            return Err(CircuitError::Cycle(self.cycle(&waiting_on)));
+        }
+        Ok(order)
+    }
+
+    // Everything never ready is in or behind a loop, and still waits on
+    // something else never ready, so following those always comes round.
+    fn cycle(&self, waiting_on: &HashMap<&'a str, usize>) -> Vec<&'a str> {
+        let stuck = |wire: &&str| waiting_on.get(wire).is_some_and(|&n| n > 0);
+        let first = waiting_on.keys().copied().filter(stuck).min();
+        let mut path = vec![first.expect("a stuck wire")];
+        loop {
+            let next = self.gates[path[path.len() - 1]]
+                .wires()
+                .find(stuck)
+                .expect("a stuck input");
+            if let Some(start) = path.iter().position(|&wire| wire == next) {
+                path.push(next);
+                return path.split_off(start);
+            }
+            path.push(next);
+        }
+    }
+
+    // the signal on every wire
+    pub fn evaluate(&self) -> Result<HashMap<&'a str, u16>, CircuitError<'a>> {
+        let mut values = HashMap::new();
+        for wire in self.order()? {
+            let value = self.gates[wire].evaluate(&values);
+            values.insert(wire, value);
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let circuit = Circuit::parse(
+            "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i",
+        )
+        .unwrap();
+        let values = circuit.evaluate().unwrap();
+        let expected = [
+            ("d", 72),
+            ("e", 507),
+            ("f", 492),
+            ("g", 114),
+            ("h", 65412),
+            ("i", 65079),
+            ("x", 123),
+            ("y", 456),
+        ];
+        assert_eq!(values, HashMap::from(expected));
+    }
+
+    #[test]
+    fn overrides() {
+        let mut circuit = Circuit::parse("1 AND b -> a\n7 -> b").unwrap();
+        assert_eq!(circuit.evaluate().unwrap()["a"], 1);
+        circuit.set("b", "6");
+        assert_eq!(circuit.evaluate().unwrap()["a"], 0);
+    }
+
+    #[test]
+    fn errors() {
+        let error = |input| match Circuit::parse(input) {
+            Ok(circuit) => circuit.evaluate().unwrap_err().to_string(),
+            Err(error) => error.to_string(),
+        };
+        assert_eq!(
+            error("x -> a\nNOT a -> b\nb OR c -> x\n1 -> c"),
+            "wires depend on each other: a <- x <- b <- a"
+        );
+        assert_eq!(
+            error("x AND 1 -> a"),
+            "wire x is used by a but never given a signal"
+        );
+        assert_eq!(
+            error("1 -> a\n2 -> a"),
+            "line 2, column 6: wire a has more than one gate"
+        );
+        assert_eq!(
+            error("1 -> a\na XOR b -> c"),
+            "line 2, column 2: expected ' -> ', found ' XOR b -> c' in a gate"
+        );
+    }
+
+    #[test]
+    fn placed() {
+        let input = "x -> a\nNOT a -> b\nb OR c -> x\n1 -> c";
+        let error = Circuit::parse(input).unwrap().evaluate().unwrap_err();
+        assert_eq!(
+            error.at(input).to_string(),
+            "line 1, column 6: wires depend on each other: a <- x <- b <- a"
+        );
+        let input = "1 -> b\nx AND b -> a";
+        let error = Circuit::parse(input).unwrap().evaluate().unwrap_err();
+        assert_eq!(
+            error.at(input).to_string(),
+            "line 2, column 1: wire x is used by a but never given a signal"
+        );
+    }
+
+    #[test]
+    fn deep() {
+        // far deeper than recursing wire by wire could go
+        let mut input = "1 -> w0".to_string();
+        for i in 1..100_000 {
+            input.push_str(&format!("\nNOT w{} -> w{i}", i - 1));
+        }
+        let circuit = Circuit::parse(&input).unwrap();
+        assert_eq!(circuit.evaluate().unwrap()["w99999"], !1);
+    }
+}
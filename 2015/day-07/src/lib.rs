@@ -0,0 +1,69 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use circuit::Circuit;
+use std::fmt::Display;
+
+pub mod circuit;
+
+// overriding b with a signal can't break a circuit that was checked when parsed
+fn signal_on_a(circuit: &Circuit) -> u16 {
+    circuit.evaluate().expect("a checked circuit")["a"]
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Circuit<'a>;
+
+    fn parse(input: &str) -> Result<Circuit<'_>, ParseError> {
+        let circuit = Circuit::parse(input)?;
+        circuit.evaluate().map_err(|error| error.at(input))?;
+        if !circuit.wires().any(|wire| wire == "a") {
+            return Err(ParseError::at(
+                input,
+                aoc_parse::end(input.trim()),
+                "expected a wire a",
+            ));
+        }
+        Ok(circuit)
+    }
+
+    fn part1(circuit: &Self::Parsed<'_>) -> impl Display {
+        signal_on_a(circuit)
+    }
+
+    fn part2(circuit: &Self::Parsed<'_>) -> impl Display {
+        // feed a back in to b and go again
+        let a = signal_on_a(circuit).to_string();
+        let mut circuit = circuit.clone();
+        circuit.set("b", &a);
+        signal_on_a(&circuit)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        let error = |input| Day::parse(input).map(|_| ()).unwrap_err().to_string();
+        assert_eq!(
+            error("b -> a\na -> b"),
+            "line 1, column 6: wires depend on each other: a <- b <- a"
+        );
+        assert_eq!(
+            error("1 -> b\nb OR c -> a"),
+            "line 2, column 6: wire c is used by a but never given a signal"
+        );
+        assert_eq!(
+            error("1 -> b\nb -> c"),
+            "line 2, column 7: expected a wire a"
+        );
+    }
+}
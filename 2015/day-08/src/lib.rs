@@ -0,0 +1,70 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+// how many characters the string literal holds once its escapes are undone
+fn unescaped_len(line: &str) -> usize {
+    let chars = line[1..line.len() - 1].chars().collect::<Vec<_>>();
+    let mut unescaped = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        let ch = chars[i];
+        i += 1;
+        unescaped += 1;
+        if ch == '\\' && i < chars.len() {
+            let ch2 = chars[i];
+            if ch2 == '\\' || ch2 == '"' {
+                i += 1; // skip escape backslash/lone quote
+            } else if ch2 == 'x' {
+                i += 3; // skip xNN
+            }
+        }
+    }
+    unescaped
+}
+
+// how long the line is as a string literal of its own
+fn escaped_len(line: &str) -> usize {
+    let extra = line.chars().filter(|&ch| ch == '\\' || ch == '"').count();
+    line.len() + 2 + extra
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    // the string literals, quotes and all
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        input
+            .trim()
+            .lines()
+            .map(|line| {
+                if line.len() >= 2 && line.starts_with('"') && line.ends_with('"') {
+                    Ok(line)
+                } else {
+                    Err(ParseError::at(input, line, "expected a quoted string"))
+                }
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Parsed<'_>) -> impl Display {
+        lines
+            .iter()
+            .map(|line| line.len() - unescaped_len(line))
+            .sum::<usize>()
+    }
+
+    fn part2(lines: &Self::Parsed<'_>) -> impl Display {
+        lines
+            .iter()
+            .map(|line| escaped_len(line) - line.len())
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,100 @@
+use aoc_parse::ParseError;
+use aoc_search::dijkstra;
+use aoc_solution::Solution;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+// the places by index, and the distance between each pair with a road
+pub struct Graph {
+    places: usize,
+    distances: HashMap<(usize, usize), u16>,
+}
+
+impl Graph {
+    // Snowdin to Straylight = 101
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let roads = input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (a, rest) = aoc_parse::split_once(input, line, " to ")?;
+                let (b, distance) = aoc_parse::split_once(input, rest, " = ")?;
+                let distance: u16 = aoc_parse::parse(input, distance, "a distance")?;
+                Ok((a, b, distance))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        let mut places = roads
+            .iter()
+            .flat_map(|&(a, b, _)| [a, b])
+            .collect::<Vec<_>>();
+        places.sort();
+        places.dedup();
+        let distances = roads
+            .iter()
+            .flat_map(|&(a, b, distance)| {
+                let a = places.binary_search(&a).expect("a place");
+                let b = places.binary_search(&b).expect("a place");
+                [((a, b), distance), ((b, a), distance)]
+            })
+            .collect();
+        Ok(Graph {
+            places: places.len(),
+            distances,
+        })
+    }
+
+    // the cheapest total cost of a route visiting every place once, with
+    // each leg costing whatever cost makes of its distance
+    fn cheapest_route(&self, cost: impl Fn(u16) -> u16) -> u16 {
+        let all_visited = (1_u32 << self.places) - 1;
+
+        // search over (current place, places visited so far), starting from
+        // nowhere so that any place can be the first one
+        let route = dijkstra(
+            (None, 0_u32),
+            |&(current, visited): &(Option<usize>, u32)| {
+                (0..self.places)
+                    .filter(|next| visited & (1 << next) == 0)
+                    .filter_map(|next| {
+                        let cost = match current {
+                            None => 0,
+                            Some(current) => cost(*self.distances.get(&(current, next))?),
+                        };
+                        Some(((Some(next), visited | (1 << next)), cost))
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |&(_, visited)| visited == all_visited,
+        )
+        .expect("a route visiting every place");
+        route.cost
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Graph;
+
+    fn parse(input: &str) -> Result<Graph, ParseError> {
+        Graph::parse(input)
+    }
+
+    fn part1(graph: &Self::Parsed<'_>) -> impl Display {
+        graph.cheapest_route(|distance| distance)
+    }
+
+    fn part2(graph: &Self::Parsed<'_>) -> impl Display {
+        // every route takes the same number of legs, so the cheapest route when
+        // each leg costs (longest leg - distance) is the longest route
+        let longest_leg = *graph.distances.values().max().expect("some distances");
+        let legs = (graph.places - 1) as u16;
+        legs * longest_leg - graph.cheapest_route(|distance| longest_leg - distance)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
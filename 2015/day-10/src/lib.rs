@@ -0,0 +1,73 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+fn look_and_say(input: &str) -> String {
+    let chars = input.chars().collect::<Vec<_>>();
+    let mut result = String::new();
+    let mut char = chars[0];
+    let mut count = 1;
+    let mut i = 1;
+    while i < chars.len() {
+        let ch = chars[i];
+        if ch == char {
+            count += 1;
+        } else {
+            result.push_str(&count.to_string());
+            result.push(char);
+            char = ch;
+            count = 1;
+        }
+        i += 1;
+    }
+    result.push_str(&count.to_string());
+    result.push(char);
+    result.to_string()
+}
+
+// how long the sequence gets after repeating look-and-say
+fn length_after(digits: &str, times: u8) -> usize {
+    let mut nums = digits.to_string();
+    for _ in 0..times {
+        nums = look_and_say(&nums);
+    }
+    nums.len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<&str, ParseError> {
+        let digits = input.trim();
+        aoc_parse::cells(input, digits, |ch| ch.is_ascii_digit().then_some(()))?;
+        Ok(digits)
+    }
+
+    fn part1(digits: &Self::Parsed<'_>) -> impl Display {
+        length_after(digits, 40)
+    }
+
+    fn part2(digits: &Self::Parsed<'_>) -> impl Display {
+        length_after(digits, 50)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let steps = ["1", "11", "21", "1211", "111221", "312211"];
+        for pair in steps.windows(2) {
+            assert_eq!(look_and_say(pair[0]), pair[1]);
+        }
+    }
+}
@@ -1,12 +1,8 @@
-fn main() {
-    let input = "hxbxwxba";
-    let answer = get_next_valid(input);
-    println!("part 1 {answer}");
-    let answer = get_next_valid(&answer);
-    println!("part 2 {answer}");
-}
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
 
-fn is_valid(chars: &Vec<char>) -> bool {
+fn is_valid(chars: &[char]) -> bool {
     // println!("check if {chars:?} valid?");
     let mut has_straight = false;
     let mut has_different_pairs = false;
@@ -39,7 +35,6 @@ fn is_valid(chars: &Vec<char>) -> bool {
 }
 
 fn get_next_valid(input: &str) -> String {
-    println!("get next valid for {input}");
     let mut chars = input.chars().collect::<Vec<_>>();
     loop {
         let mut i = chars.len() - 1;
@@ -63,29 +58,50 @@ fn get_next_valid(input: &str) -> String {
     chars.into_iter().collect()
 }
 
+pub struct Day;
+
+impl Solution for Day {
+    // the current password
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<&str, ParseError> {
+        let password = input.trim();
+        aoc_parse::cells(input, password, |ch| ch.is_ascii_lowercase().then_some(()))?;
+        Ok(password)
+    }
+
+    fn part1(password: &Self::Parsed<'_>) -> impl Display {
+        get_next_valid(password)
+    }
+
+    // santa's password expires again
+    fn part2(password: &Self::Parsed<'_>) -> impl Display {
+        get_next_valid(&get_next_valid(password))
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use test_case::test_case;
-
-    #[test_case("abcdefgh", "abcdffaa".to_string(); "example 1")]
-    #[test_case("ghijklmn", "ghjaabcc".to_string(); "example 2")]
-    fn next_valid(input: &str, expected: String) {
-        let next_valid = get_next_valid(input);
-        assert_eq!(next_valid, expected);
-        assert_eq!(is_valid(&next_valid.chars().collect()), true);
-    }
 
-    #[test_case("abcdffaa"; "example 1")]
-    #[test_case("ghjaabcc"; "example 2")]
-    fn valid(input: &str) {
-        assert_eq!(is_valid(&input.chars().collect()), true);
+    fn valid(password: &str) -> bool {
+        is_valid(&password.chars().collect::<Vec<_>>())
     }
 
-    #[test_case("hijklmmn"; "contains i and l")]
-    #[test_case("abbceffg"; "no straight")]
-    #[test_case("abbcegjk"; "only has one double letter")]
-    fn not_valid(input: &str) {
-        assert_eq!(is_valid(&input.chars().collect()), false);
+    #[test]
+    fn examples() {
+        assert!(valid("abcdffaa"));
+        assert!(valid("ghjaabcc"));
+        // contains i and l
+        assert!(!valid("hijklmmn"));
+        // no straight
+        assert!(!valid("abbceffg"));
+        // only has one double letter
+        assert!(!valid("abbcegjk"));
     }
 }
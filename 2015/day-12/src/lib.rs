@@ -0,0 +1,72 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use serde_json::Value;
+use std::fmt::Display;
+
+fn parse_input(input: &str) -> Result<Value, ParseError> {
+    serde_json::from_str(input).map_err(|error| {
+        // serde puts the position on the end of its own message
+        let message = error.to_string();
+        let message = message.split(" at line ").next().unwrap_or_default();
+        ParseError {
+            line: error.line().max(1),
+            column: error.column().max(1),
+            len: 1,
+            message: format!("invalid JSON: {message}"),
+        }
+    })
+}
+
+// part 2 ignores any object with a "red" value, and everything inside it.
+// The puzzle only has whole numbers, but any JSON number adds up as a float,
+// which still shows whole sums without a fraction
+fn sum_numbers(value: &Value, skip_red: bool) -> f64 {
+    match value {
+        Value::Number(num) => num.as_f64().unwrap_or_default(),
+        Value::Array(values) => values.iter().map(|v| sum_numbers(v, skip_red)).sum(),
+        Value::Object(map) => {
+            if skip_red && map.values().any(|k| k == "red") {
+                0.0
+            } else {
+                map.values().map(|v| sum_numbers(v, skip_red)).sum()
+            }
+        }
+        _ => 0.0,
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Value;
+
+    fn parse(input: &str) -> Result<Value, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(json: &Self::Parsed<'_>) -> impl Display {
+        sum_numbers(json, false)
+    }
+
+    fn part2(json: &Self::Parsed<'_>) -> impl Display {
+        sum_numbers(json, true)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_solution::part1;
+
+    #[test]
+    fn any_numbers() {
+        let input = r#"[1.5, 2, {"a": 1e2}]"#;
+        assert_eq!(part1::<Day>(input), Ok("103.5".to_string()));
+        assert_eq!(part1::<Day>("[1.5, -2]"), Ok("-0.5".to_string()));
+    }
+}
@@ -0,0 +1,4 @@
+// one test per file in examples/
+fn main() {
+    aoc_solution::examples::generate();
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_parse::{Context, ParseError};
+use aoc_solution::Solution;
+use itertools::Itertools;
+
+// how much happier the first person is sitting next to the second
+type Happiness<'a> = HashMap<(&'a str, &'a str), i32>;
+
+// Alice would lose 57 happiness units by sitting next to Bob.
+fn parse_input(input: &str) -> Result<Happiness<'_>, ParseError> {
+    aoc_parse::lines(input, input, |cursor| {
+        let person_a = cursor.word()?;
+        cursor.tag(" would ")?;
+        let sign = cursor.one_of(&[("gain", 1), ("lose", -1)])?;
+        cursor.tag(" ")?;
+        let happiness = sign * cursor.int::<i32>()?;
+        cursor.tag(" happiness units by sitting next to ")?;
+        let person_b = cursor.word()?;
+        cursor.tag(".")?;
+        Ok(((person_a, person_b), happiness))
+    })
+    .context("a seating preference")
+}
+
+fn best_arrangement(happiness: &Happiness) -> i32 {
+    let names = happiness.keys().map(|k| k.0).unique().collect::<Vec<_>>();
+    // this definitely contains unnecessary duplicates due to the way the seating
+    // arrangement wraps from start to finish, but for this it's not worth the
+    // trouble of finding and removing them
+    let combinations = names.iter().permutations(names.len()).collect::<Vec<_>>();
+
+    let mut best = i32::MIN;
+    for combination in combinations {
+        let mut total = 0;
+        // first and last
+        total += happiness[&(*combination[0], *combination[combination.len() - 1])];
+        total += happiness[&(*combination[combination.len() - 1], *combination[0])];
+
+        // now every person in between
+        for i in 0..combination.len() - 1 {
+            total += happiness[&(*combination[i], *combination[i + 1])];
+            total += happiness[&(*combination[i + 1], *combination[i])];
+        }
+
+        best = best.max(total);
+    }
+    best
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Happiness<'a>;
+
+    fn parse(input: &str) -> Result<Happiness<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(happiness: &Self::Parsed<'_>) -> impl Display {
+        best_arrangement(happiness)
+    }
+
+    // I don't mind who I sit next to, and nobody minds me
+    fn part2(happiness: &Self::Parsed<'_>) -> impl Display {
+        let mut happiness = happiness.clone();
+        let names = happiness
+            .keys()
+            .map(|(name, _)| *name)
+            .unique()
+            .collect::<Vec<_>>();
+        for name in names {
+            happiness.insert(("me", name), 0);
+            happiness.insert((name, "me"), 0);
+        }
+        best_arrangement(&happiness)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
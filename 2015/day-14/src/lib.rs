@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_parse::{Context, ParseError};
+use aoc_solution::Solution;
+// how long the real race lasts
+const RACE_SECS: u32 = 2503;
+
+#[derive(Debug)]
+pub struct FlightSpeed {
+    kms_per_sec: u32,
+    num_sec: u32,
+    rest_secs: u32,
+}
+
+// Rudolph can fly 22 km/s for 8 seconds, but then must rest for 165 seconds.
+fn parse_input(input: &str) -> Result<HashMap<&str, FlightSpeed>, ParseError> {
+    aoc_parse::lines(input, input, |cursor| {
+        let name = cursor.word()?;
+        cursor.tag(" can fly ")?;
+        let kms_per_sec = cursor.int()?;
+        cursor.tag(" km/s for ")?;
+        let num_sec = cursor.int()?;
+        cursor.tag(" seconds, but then must rest for ")?;
+        let rest_secs = cursor.int()?;
+        cursor.tag(" seconds.")?;
+        Ok((
+            name,
+            FlightSpeed {
+                kms_per_sec,
+                num_sec,
+                rest_secs,
+            },
+        ))
+    })
+    .context("a reindeer")
+}
+
+fn get_distance(
+    secs: &u32,
+    FlightSpeed {
+        kms_per_sec,
+        num_sec,
+        rest_secs,
+    }: &FlightSpeed,
+) -> u32 {
+    let cycle_secs = num_sec + rest_secs; // how many seconds are there in a complete flight + rest cycle?
+    let remainder_secs = secs % cycle_secs; // how many seconds are there left after all the whole cycles that fit into `secs`?
+    let num_cycles = (secs - remainder_secs) / cycle_secs; // how many whole cycles are possible in `secs`?
+    let whole_cycle_kms = num_cycles * num_sec * kms_per_sec; // how many kms are covered in the whole cycles?
+    let remainder_kms = num_sec.min(&remainder_secs) * kms_per_sec; // how many additional kms are covered in the final (possibly partial cycle) seconds?
+    whole_cycle_kms + remainder_kms
+}
+
+pub fn max_distance(reindeers: &HashMap<&str, FlightSpeed>, secs: u32) -> u32 {
+    reindeers
+        .values()
+        .map(|flight_speed| get_distance(&secs, flight_speed))
+        .max()
+        .unwrap_or(0)
+}
+
+// a point to whoever's in the lead at the end of each second
+pub fn max_points(reindeers: &HashMap<&str, FlightSpeed>, secs: u32) -> u32 {
+    let mut points = HashMap::new();
+    let mut distances = HashMap::new();
+    for i in 1..=secs {
+        for (name, flight_speed) in reindeers.iter() {
+            let distance = get_distance(&i, flight_speed);
+            distances.insert(name, distance);
+        }
+
+        let winning_distance = distances.values().max().unwrap();
+        for name in reindeers.keys() {
+            if distances[&name] == *winning_distance {
+                points.entry(name).and_modify(|p| *p += 1).or_insert(1);
+            }
+        }
+    }
+
+    *points.values().max().unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    // the reindeer and how long the race lasts
+    type Parsed<'a> = (HashMap<&'a str, FlightSpeed>, u32);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok((parse_input(input)?, RACE_SECS))
+    }
+
+    fn part1((reindeers, secs): &Self::Parsed<'_>) -> impl Display {
+        max_distance(reindeers, *secs)
+    }
+
+    fn part2((reindeers, secs): &Self::Parsed<'_>) -> impl Display {
+        max_points(reindeers, *secs)
+    }
+
+    fn set((_, secs): &mut Self::Parsed<'_>, name: &str, value: u64) -> bool {
+        match (name, u32::try_from(value)) {
+            ("seconds", Ok(value)) => {
+                *secs = value;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_parse::{Context, Cursor, ParseError};
+use aoc_solution::Solution;
+#[derive(Debug, Default)]
+pub struct Properties {
+    capacity: i8,
+    durability: i8,
+    flavor: i8,
+    texture: i8,
+    calories: i8,
+}
+
+type Ingredients<'a> = HashMap<&'a str, Properties>;
+
+// Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+fn parse_input(input: &str) -> Result<Ingredients<'_>, ParseError> {
+    aoc_parse::lines(input, input, |cursor| {
+        let name = cursor.word()?;
+        cursor.tag(": ")?;
+        let mut properties = Properties::default();
+        for (key, value) in cursor.fields(" ", Cursor::int)? {
+            *match key {
+                "capacity" => &mut properties.capacity,
+                "durability" => &mut properties.durability,
+                "flavor" => &mut properties.flavor,
+                "texture" => &mut properties.texture,
+                "calories" => &mut properties.calories,
+                _ => {
+                    let message = format!("unknown property '{key}'");
+                    return Err(ParseError::at(input, key, message));
+                }
+            } = value;
+        }
+        Ok((name, properties))
+    })
+    .context("an ingredient")
+}
+
+fn calculate_properties(recipe: &HashMap<&&str, u8>, ingredients: &Ingredients) -> (i32, i32) {
+    let mut capacity = 0;
+    let mut durability = 0;
+    let mut flavor = 0;
+    let mut texture = 0;
+    let mut calories = 0;
+    for (name, properties) in ingredients {
+        if let Some(teaspoons) = recipe.get(name) {
+            capacity += properties.capacity as i32 * *teaspoons as i32;
+            flavor += properties.flavor as i32 * *teaspoons as i32;
+            durability += properties.durability as i32 * *teaspoons as i32;
+            texture += properties.texture as i32 * *teaspoons as i32;
+            calories += properties.calories as i32 * *teaspoons as i32;
+        }
+    }
+
+    (
+        capacity.max(0) * durability.max(0) * flavor.max(0) * texture.max(0),
+        calories,
+    )
+}
+
+// every way of sharing the teaspoons out between that many ingredients
+fn recipes(ingredients: usize, teaspoons: u8) -> Vec<Vec<u8>> {
+    match ingredients {
+        0 => vec![],
+        1 => vec![vec![teaspoons]],
+        _ => (0..=teaspoons)
+            .flat_map(|first| {
+                recipes(ingredients - 1, teaspoons - first)
+                    .into_iter()
+                    .map(move |mut rest| {
+                        rest.push(first);
+                        rest
+                    })
+            })
+            .collect(),
+    }
+}
+
+// the best score from a hundred teaspoons of the ingredients, only counting
+// recipes with exactly the given calories if there are any
+fn best_recipe(ingredients: &Ingredients, only_calories: Option<i32>) -> i32 {
+    let names = ingredients.keys().collect::<Vec<_>>();
+    recipes(names.len(), 100)
+        .into_iter()
+        .filter_map(|teaspoons| {
+            let recipe = names.iter().copied().zip(teaspoons).collect();
+            let (score, calories) = calculate_properties(&recipe, ingredients);
+            only_calories
+                .is_none_or(|only| only == calories)
+                .then_some(score)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Ingredients<'a>;
+
+    fn parse(input: &str) -> Result<Ingredients<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(ingredients: &Self::Parsed<'_>) -> impl Display {
+        best_recipe(ingredients, None)
+    }
+
+    fn part2(ingredients: &Self::Parsed<'_>) -> impl Display {
+        best_recipe(ingredients, Some(500))
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use aoc_parse::{Context, Cursor, ParseError};
+use aoc_solution::Solution;
+type Things<'a> = HashMap<&'a str, usize>;
+
+fn parse_analysis(input: &str) -> Result<Things<'_>, ParseError> {
+    aoc_parse::lines(input, input, |cursor| {
+        let thing = cursor.word()?;
+        cursor.tag(": ")?;
+        Ok((thing, cursor.int()?))
+    })
+}
+
+/*
+Sue 241: trees: 2, goldfish: 8, cars: 1
+Sue 242: perfumes: 2, cars: 0, akitas: 10
+Sue 243: pomeranians: 1, cars: 7, trees: 2
+Sue 244: trees: 9, vizslas: 2, akitas: 10
+ */
+fn parse_sues(input: &str) -> Result<Vec<Things<'_>>, ParseError> {
+    aoc_parse::lines(input, input, |cursor| {
+        cursor.tag("Sue ")?;
+        cursor.int::<usize>()?;
+        cursor.tag(": ")?;
+        Ok(cursor.fields(": ", Cursor::int)?.into_iter().collect())
+    })
+    .context("a sue")
+}
+
+const ANALYSIS: &str = "children: 3
+cats: 7
+samoyeds: 2
+pomeranians: 3
+akitas: 0
+vizslas: 0
+goldfish: 5
+trees: 3
+cars: 2
+perfumes: 1";
+
+// the number of the first sue whose things all match the analysis, by
+// whatever matching means
+fn find_sue(sues: &[Things], matches: impl Fn(&str, usize, usize) -> bool) -> usize {
+    let analysis = parse_analysis(ANALYSIS).expect("a valid analysis");
+    sues.iter()
+        .position(|sue| {
+            analysis.iter().all(|(k, v)| match sue.get(k) {
+                Some(sue_v) => matches(k, *sue_v, *v),
+                None => true,
+            })
+        })
+        .expect("a matching sue")
+        + 1
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Things<'a>>;
+
+    fn parse(input: &str) -> Result<Vec<Things<'_>>, ParseError> {
+        parse_sues(input)
+    }
+
+    fn part1(sues: &Self::Parsed<'_>) -> impl Display {
+        find_sue(sues, |_, sue_v, v| sue_v == v)
+    }
+
+    // the machine's readings for some things are really ranges
+    fn part2(sues: &Self::Parsed<'_>) -> impl Display {
+        find_sue(sues, |k, sue_v, v| match k {
+            "cats" | "trees" => sue_v > v,
+            "pomeranians" | "goldfish" => sue_v < v,
+            _ => sue_v == v,
+        })
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
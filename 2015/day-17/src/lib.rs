@@ -0,0 +1,87 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+// how much eggnog there is to store
+const LITERS: u8 = 150;
+
+// each combination of containers as a bitmask, for those that hold exactly
+// the eggnog
+fn combinations(liters: u8, containers: &[u8]) -> impl Iterator<Item = usize> + '_ {
+    (0..2_usize.pow(containers.len() as u32)).filter(move |i| {
+        containers
+            .iter()
+            .enumerate()
+            .filter_map(|(j, c)| (i >> j & 1 == 1).then_some(*c as u16))
+            .sum::<u16>()
+            == liters as u16
+    })
+}
+
+pub fn count_combinations(liters: u8, containers: &[u8]) -> usize {
+    combinations(liters, containers).count()
+}
+
+// only the combinations using as few containers as possible
+pub fn count_min_combinations(liters: u8, containers: &[u8]) -> usize {
+    let mut min_containers = None;
+    let mut num_combinations = 0;
+    for i in combinations(liters, containers) {
+        match (min_containers, i.count_ones()) {
+            (None, num_containers) => {
+                min_containers = Some(num_containers);
+                num_combinations = 1;
+            }
+            (Some(prev_min), num_containers) if num_containers < prev_min => {
+                min_containers = Some(num_containers);
+                num_combinations = 1;
+            }
+            (Some(prev_min), num_containers) if num_containers == prev_min => {
+                num_combinations += 1;
+            }
+            (Some(prev_min), num_containers) => {
+                assert!(num_containers > prev_min);
+            }
+        }
+    }
+    num_combinations
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    // the container sizes and how much eggnog there is
+    type Parsed<'a> = (Vec<u8>, u8);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let containers = input
+            .trim()
+            .lines()
+            .map(|line| aoc_parse::parse(input, line, "a container size"))
+            .collect::<Result<_, _>>()?;
+        Ok((containers, LITERS))
+    }
+
+    fn part1((containers, liters): &Self::Parsed<'_>) -> impl Display {
+        count_combinations(*liters, containers)
+    }
+
+    fn part2((containers, liters): &Self::Parsed<'_>) -> impl Display {
+        count_min_combinations(*liters, containers)
+    }
+
+    fn set((_, liters): &mut Self::Parsed<'_>, name: &str, value: u64) -> bool {
+        match (name, u8::try_from(value)) {
+            ("liters", Ok(value)) => {
+                *liters = value;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,196 @@
+use aoc_cycle::nth_state;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+// how many steps the real animation runs for
+const STEPS: usize = 100;
+
+type Lights = Vec<Vec<u8>>;
+
+fn step(lights: &[Vec<u8>]) -> Lights {
+    let row_len = lights.len();
+    let col_len = lights[0].len();
+    let mut new_lights = Vec::with_capacity(row_len);
+    for y in 0..row_len {
+        let inner = (0..col_len).map(|_| 0).collect::<Vec<_>>();
+        new_lights.push(inner);
+        for x in 0..col_len {
+            let num_neighbours_on = [
+                if y > 0 { Some(lights[y - 1][x]) } else { None }, // North
+                if y > 0 && x + 1 < col_len {
+                    // North-East
+                    Some(lights[y - 1][x + 1])
+                } else {
+                    None
+                },
+                if x + 1 < col_len {
+                    // East
+                    Some(lights[y][x + 1])
+                } else {
+                    None
+                },
+                if y + 1 < row_len && x + 1 < col_len {
+                    // South-East
+                    Some(lights[y + 1][x + 1])
+                } else {
+                    None
+                },
+                if y + 1 < row_len {
+                    // South
+                    Some(lights[y + 1][x])
+                } else {
+                    None
+                },
+                if y + 1 < row_len && x > 0 {
+                    // South-West
+                    Some(lights[y + 1][x - 1])
+                } else {
+                    None
+                },
+                if x > 0 { Some(lights[y][x - 1]) } else { None }, // West
+                if y > 0 && x > 0 {
+                    // North-West
+                    Some(lights[y - 1][x - 1])
+                } else {
+                    None
+                },
+            ]
+            .into_iter()
+            .flatten()
+            .sum();
+
+            let light = lights[y][x];
+            new_lights[y][x] = match (light, num_neighbours_on) {
+                (1, 2 | 3) => 1,
+                (1, _) => 0,
+                (0, 3) => 1,
+                (0, _) => 0,
+                _ => panic!(
+                    "unhandled state of light {light} and num_neighbours_on {num_neighbours_on}"
+                ),
+            }
+        }
+    }
+    new_lights
+}
+
+// the corners are stuck on in part 2 (but not already all on in the input)
+fn stick_corners(mut lights: Lights) -> Lights {
+    let row_len = lights.len();
+    let col_len = lights[0].len();
+    lights[0][0] = 1;
+    lights[row_len - 1][0] = 1;
+    lights[row_len - 1][col_len - 1] = 1;
+    lights[0][col_len - 1] = 1;
+    lights
+}
+
+// the lights soon settle down, so any number of steps takes about as long
+pub fn lights_on(lights: &Lights, steps: usize, stuck_corners: bool) -> usize {
+    let fix = |lights| {
+        if stuck_corners {
+            stick_corners(lights)
+        } else {
+            lights
+        }
+    };
+    let lights = nth_state(fix(lights.clone()), steps, |lights| fix(step(lights)));
+    lights
+        .iter()
+        .flat_map(|r| r.iter().map(|l| *l as usize))
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    // the lights and how many steps the animation runs for
+    type Parsed<'a> = (Lights, usize);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let lines = input.trim().lines().collect::<Vec<_>>();
+        let Some(width) = lines.first().map(|line| line.len()) else {
+            return Err(ParseError::at(input, input, "expected some lights"));
+        };
+        let lights = lines
+            .into_iter()
+            .map(|line| {
+                if line.len() != width {
+                    let message = format!("expected {width} lights in each row");
+                    return Err(ParseError::at(input, line, message));
+                }
+                aoc_parse::cells(input, line, |ch| match ch {
+                    '#' => Some(1),
+                    '.' => Some(0),
+                    _ => None,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((lights, STEPS))
+    }
+
+    fn part1((lights, steps): &Self::Parsed<'_>) -> impl Display {
+        lights_on(lights, *steps, false)
+    }
+
+    fn part2((lights, steps): &Self::Parsed<'_>) -> impl Display {
+        lights_on(lights, *steps, true)
+    }
+
+    fn set((_, steps): &mut Self::Parsed<'_>, name: &str, value: u64) -> bool {
+        match (name, usize::try_from(value)) {
+            ("steps", Ok(value)) => {
+                *steps = value;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        let error = |input| Day::parse(input).map(|_| ()).unwrap_err().to_string();
+        assert_eq!(error("\n"), "line 1, column 1: expected some lights");
+        assert_eq!(
+            error("#.#\n.#"),
+            "line 2, column 1: expected 3 lights in each row"
+        );
+    }
+
+    #[test]
+    fn settles() {
+        let input = ".#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+        let (lights, _) = Day::parse(input).unwrap();
+        // by then it's a block, which never changes
+        assert_eq!(lights_on(&lights, 1_000_000_000, false), 4);
+    }
+
+    #[test]
+    fn settles_stuck() {
+        let input = "##.#.#
+...##.
+#....#
+..#...
+#.#..#
+####.#";
+        let (lights, _) = Day::parse(input).unwrap();
+        // it settles down to 7 lights by step 20
+        assert_eq!(lights_on(&lights, 1_000_000_000, true), 7);
+    }
+}
@@ -0,0 +1,93 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+fn to_num_str(num: &str) -> &str {
+    match num {
+        "one" => "1",
+        "two" => "2",
+        "three" => "3",
+        "four" => "4",
+        "five" => "5",
+        "six" => "6",
+        "seven" => "7",
+        "eight" => "8",
+        "nine" => "9",
+        _ => num,
+    }
+}
+
+const NUM_STR: [&str; 19] = [
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "one", "two", "three", "four", "five", "six",
+    "seven", "eight", "nine",
+];
+
+fn find_num(s: &str, first: bool) -> &str {
+    if first {
+        let mut min_index = s.len();
+        let mut min_num: Option<&str> = None;
+        for num in NUM_STR {
+            if let Some(index) = s.find(num) {
+                if index < min_index {
+                    min_index = index;
+                    min_num = Some(num);
+                }
+            }
+        }
+        return min_num.unwrap();
+    }
+
+    let mut max_index: usize = 0;
+    let mut max_num: Option<&str> = None;
+    for num in NUM_STR {
+        if let Some(index) = s.rfind(num) {
+            if index >= max_index {
+                max_index = index;
+                max_num = Some(num);
+            }
+        }
+    }
+    max_num.unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(lines: &Self::Parsed<'_>) -> impl Display {
+        lines
+            .iter()
+            .map(|line| {
+                let first_num_char = line.chars().find(|c| c.is_ascii_digit()).unwrap();
+                let last_num_char = line.chars().rev().find(|c| c.is_ascii_digit()).unwrap();
+                let result: u64 = format!("{}{}", first_num_char, last_num_char)
+                    .parse()
+                    .unwrap();
+                result
+            })
+            .sum::<u64>()
+    }
+
+    // some of the digits are spelled out
+    fn part2(lines: &Self::Parsed<'_>) -> impl Display {
+        lines
+            .iter()
+            .map(|line| {
+                let first = to_num_str(find_num(line, true));
+                let last = to_num_str(find_num(line, false));
+                let result: u64 = format!("{}{}", first, last).parse().unwrap();
+                result
+            })
+            .sum::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,98 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Game {
+    game_num: u64,
+    sets: Vec<Set>,
+}
+
+#[derive(Debug, Default)]
+struct Set {
+    red: u64,
+    green: u64,
+    blue: u64,
+}
+
+// Game 1: 8 green, 4 red, 4 blue; 1 green, 6 red, 4 blue; 7 red, 4 green, 1 blue; 2 blue, 8 red, 8 green
+fn parse_game(input: &str, line: &str) -> Result<Game, ParseError> {
+    let (game_num, sets) = aoc_parse::split_once(input, line, ": ")?;
+    let game_num = aoc_parse::strip_prefix(input, game_num, "Game ")?;
+    let sets = sets
+        .split("; ")
+        .map(|set| {
+            set.split(", ").try_fold(Set::default(), |acc, cubes| {
+                let (num, rgb) = aoc_parse::split_once(input, cubes, " ")?;
+                let num = aoc_parse::parse(input, num, "a number of cubes")?;
+                match rgb {
+                    "red" => Ok(Set { red: num, ..acc }),
+                    "green" => Ok(Set { green: num, ..acc }),
+                    "blue" => Ok(Set { blue: num, ..acc }),
+                    _ => Err(ParseError::at(input, rgb, "expected red, green or blue")),
+                }
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Game {
+        game_num: aoc_parse::parse(input, game_num, "a game number")?,
+        sets,
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        input.lines().map(|line| parse_game(input, line)).collect()
+    }
+
+    // the games possible with only 12 red, 13 green and 14 blue cubes
+    fn part1(games: &Self::Parsed<'_>) -> impl Display {
+        games
+            .iter()
+            .filter(|Game { sets, .. }| {
+                sets.iter()
+                    .all(|set| set.red <= 12 && set.green <= 13 && set.blue <= 14)
+            })
+            .map(|Game { game_num, .. }| game_num)
+            .sum::<u64>()
+    }
+
+    // the power of the fewest cubes that make each game possible
+    fn part2(games: &Self::Parsed<'_>) -> impl Display {
+        games
+            .iter()
+            .map(|Game { sets, .. }| {
+                let min_cubes = sets.iter().fold(Set::default(), |acc, set| Set {
+                    red: acc.red.max(set.red),
+                    green: acc.green.max(set.green),
+                    blue: acc.blue.max(set.blue),
+                });
+                min_cubes.red * min_cubes.green * min_cubes.blue
+            })
+            .sum::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_solution::part1;
+
+    #[test]
+    fn invalid() {
+        let error = part1::<Day>("Game 1: 3 blue, 4 purple").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 19: expected red, green or blue"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+mod part1;
+mod part2;
+
+#[derive(Debug)]
+pub enum Item {
+    PartNum { num: u32, x_from: u8, x_to: u8 },
+    Symbol { ch: char, x: u8 },
+}
+
+pub type Row = Vec<Item>;
+
+pub type Schematic = Vec<Row>;
+
+fn parse_line(line: &str) -> Row {
+    let mut items: Vec<Item> = Vec::new();
+    let chars: Vec<char> = line.chars().collect();
+    let mut i: usize = 0;
+    while i < line.len() {
+        let ch = chars[i];
+        if ch != '.' {
+            if ch.is_ascii_digit() {
+                let x_from = i as u8;
+                let mut num = String::from(ch);
+                while i + 1 < line.len() {
+                    let ch = chars[i + 1];
+                    if ch.is_ascii_digit() {
+                        num.push(ch);
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+                items.push(Item::PartNum {
+                    num: num.parse().unwrap(),
+                    x_from,
+                    x_to: (x_from + (num.len() - 1) as u8),
+                })
+            } else {
+                items.push(Item::Symbol { ch, x: (i) as u8 });
+            }
+        }
+        i += 1;
+    }
+    items
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Schematic;
+
+    // anything but a digit or . is a symbol, so there's nothing to reject
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        Ok(input.lines().map(parse_line).collect())
+    }
+
+    fn part1(schematic: &Self::Parsed<'_>) -> impl Display {
+        part1::get_answer(schematic)
+    }
+
+    fn part2(schematic: &Self::Parsed<'_>) -> impl Display {
+        part2::get_answer(schematic)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
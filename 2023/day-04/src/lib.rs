@@ -0,0 +1,109 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Card {
+    card_num: u8,
+    num_matches: u8,
+}
+
+fn num_matches(winning_numbers: &[u8], game_numbers: &[u8]) -> u8 {
+    winning_numbers
+        .iter()
+        .map(|number| {
+            if game_numbers.contains(number) {
+                return 1;
+            }
+            0
+        })
+        .sum()
+}
+
+pub type Cards = Vec<Card>;
+
+// Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+fn parse_line(input: &str, line: &str) -> Result<Card, ParseError> {
+    let (card_num, numbers) = aoc_parse::split_once(input, line, ": ")?;
+    let card_num = aoc_parse::strip_prefix(input, card_num, "Card")?.trim_start();
+    let card_num = aoc_parse::parse(input, card_num, "a card number")?;
+    let (winning_numbers, game_numbers) = aoc_parse::split_once(input, numbers, " | ")?;
+    let winning_numbers = parse_numbers(input, winning_numbers)?;
+    let game_numbers = parse_numbers(input, game_numbers)?;
+    let num_matches = num_matches(&winning_numbers, &game_numbers);
+    Ok(Card {
+        card_num,
+        num_matches,
+    })
+}
+
+// 41 48 83 86 17
+fn parse_numbers(input: &str, numbers: &str) -> Result<Vec<u8>, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|number| aoc_parse::parse(input, number, "a number"))
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Cards, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| parse_line(input, line))
+        .collect()
+}
+
+// each card wins copies of the cards after it, which can win more copies
+fn total_cards(original_cards: &Cards) -> usize {
+    let mut total_cards = 0usize;
+    let mut unprocessed_cards: Vec<&Card> = original_cards.iter().collect();
+    loop {
+        total_cards += unprocessed_cards.len();
+        let mut new_cards: Vec<&Card> = Vec::new(); // won in this cycle
+        for card in unprocessed_cards {
+            // if it's a winner add the following N cards
+            let num_matches = card.num_matches;
+            if num_matches > 0 {
+                let to_add = (card.card_num)..(card.card_num + num_matches);
+                for j in to_add {
+                    new_cards.push(original_cards.get((j) as usize).unwrap())
+                }
+            }
+        }
+        if new_cards.is_empty() {
+            break;
+        }
+        unprocessed_cards = new_cards;
+    }
+    total_cards
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Cards;
+
+    fn parse(input: &str) -> Result<Cards, ParseError> {
+        parse_input(input)
+    }
+
+    // the first match is worth a point, and each one after that doubles it
+    fn part1(cards: &Self::Parsed<'_>) -> impl Display {
+        cards
+            .iter()
+            .map(|card| match card.num_matches {
+                0 => 0,
+                num_matches => 1_u64 << (num_matches - 1),
+            })
+            .sum::<u64>()
+    }
+
+    fn part2(cards: &Self::Parsed<'_>) -> impl Display {
+        total_cards(cards)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,147 @@
+use aoc_parse::ParseError;
+use aoc_ranges::RangeSet;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+// represents one entry in a list like
+// seed-to-soil map:
+// 50 98 2
+#[derive(Debug)]
+struct Mapping {
+    dest_start: usize,
+    source_start: usize,
+    len: usize,
+}
+
+impl Mapping {
+    // 50 98 2
+    fn parse(input: &str, line: &str) -> Result<Mapping, ParseError> {
+        let mut numbers = line.split(' ');
+        let mut number = |expected| {
+            let number = numbers.next().unwrap_or(aoc_parse::end(line));
+            aoc_parse::parse(input, number, expected)
+        };
+        Ok(Mapping {
+            dest_start: number("a destination start")?,
+            source_start: number("a source start")?,
+            len: number("a length")?,
+        })
+    }
+
+    // the values covered by a layer of mappings moved to their destinations,
+    // with anything no mapping covers passing straight through
+    fn map_ranges(mappings: &[Mapping], sources: &Seeds) -> Seeds {
+        let mut unmapped = sources.clone();
+        let mut mapped = RangeSet::new();
+        for mapping in mappings {
+            let source = mapping.source_start..mapping.source_start + mapping.len;
+            let (moved, rest) = unmapped.map(source, mapping.dest_start);
+            mapped = mapped.union(&moved);
+            unmapped = rest;
+        }
+        mapped.union(&unmapped)
+    }
+}
+
+type Seeds = RangeSet<usize>;
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    // each map in turn, from seed-to-soil through to humidity-to-location
+    maps: Vec<Vec<Mapping>>,
+}
+
+impl Almanac {
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        let mut lines = input.trim().lines();
+        let seeds_line = lines.next().unwrap_or(aoc_parse::end(input));
+        let seeds: Vec<usize> = aoc_parse::strip_prefix(input, seeds_line, "seeds: ")?
+            .split(' ')
+            .map(|seed| aoc_parse::parse(input, seed, "a seed"))
+            .collect::<Result<_, _>>()?;
+
+        let mut maps: Vec<Vec<Mapping>> = Vec::new();
+        for line in lines {
+            if line.is_empty() {
+                continue;
+            }
+            if line.ends_with(" map:") {
+                maps.push(Vec::new());
+                continue;
+            }
+            let Some(map) = maps.last_mut() else {
+                return Err(ParseError::at(input, line, "expected a map"));
+            };
+            map.push(Mapping::parse(input, line)?);
+        }
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn closest_location(&self, seeds: Seeds) -> usize {
+        self.maps
+            .iter()
+            .fold(seeds, |ranges, map| Mapping::map_ranges(map, &ranges))
+            .min()
+            .expect("closest location")
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Almanac;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        Almanac::parse(input)
+    }
+
+    fn part1(almanac: &Self::Parsed<'_>) -> impl Display {
+        let seeds = almanac.seeds.iter().map(|&seed| seed..seed + 1).collect();
+        almanac.closest_location(seeds)
+    }
+
+    fn part2(almanac: &Self::Parsed<'_>) -> impl Display {
+        // billions of seeds, but only a handful of ranges of them
+        let seeds = almanac
+            .seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect();
+        almanac.closest_location(seeds)
+    }
+
+    // part 2 reads the seeds in pairs, a start and then a length
+    fn check(input: &str, almanac: &Self::Parsed<'_>, part: u8) -> Result<(), ParseError> {
+        if part == 2 && !almanac.seeds.len().is_multiple_of(2) {
+            let seeds_line = input.trim().lines().next().unwrap_or(input);
+            return Err(ParseError::at(
+                input,
+                aoc_parse::end(seeds_line),
+                "expected a length for the last seed range",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_solution::{part1, part2};
+
+    #[test]
+    fn odd_seeds() {
+        let input = "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n";
+        assert_eq!(part1::<Day>(input), Ok("14".to_string()));
+        assert_eq!(
+            part2::<Day>(input).unwrap_err().to_string(),
+            "line 1, column 16: expected a length for the last seed range"
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Race {
+    time: u64,
+    distance_record: u64,
+}
+
+impl Race {
+    fn num_winners(&self) -> u64 {
+        let mut num_winners = 0;
+        // probably a more efficient way would be to search outwards (in both directions)
+        // from the middle of the range and stop searching once we are no longer finding winners.
+        for hold_time in 1..self.time {
+            let time_remaining = self.time - hold_time;
+            let distance = time_remaining * hold_time;
+            if distance > self.distance_record {
+                num_winners += 1;
+            }
+        }
+        num_winners
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = input.trim().lines();
+    let mut numbers = |label, expected| -> Result<Vec<u64>, ParseError> {
+        let line = lines.next().unwrap_or(aoc_parse::end(input));
+        aoc_parse::strip_prefix(input, line, label)?
+            .split_whitespace()
+            .map(|number| aoc_parse::parse(input, number, expected))
+            .collect()
+    };
+    let times = numbers("Time:", "a time")?;
+    let distances = numbers("Distance:", "a distance")?;
+    Ok(times
+        .iter()
+        .zip(distances.iter())
+        .map(|(time, distance_record)| Race {
+            time: *time,
+            distance_record: *distance_record,
+        })
+        .collect())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Race>;
+
+    fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(races: &Self::Parsed<'_>) -> impl Display {
+        races.iter().map(Race::num_winners).product::<u64>()
+    }
+
+    // the spaces between the races were just bad kerning
+    fn part2(races: &Self::Parsed<'_>) -> impl Display {
+        Race {
+            time: joined(races, |race| race.time).expect("a checked time"),
+            distance_record: joined(races, |race| race.distance_record)
+                .expect("a checked distance"),
+        }
+        .num_winners()
+    }
+
+    fn check(input: &str, races: &Self::Parsed<'_>, part: u8) -> Result<(), ParseError> {
+        if part != 2 {
+            return Ok(());
+        }
+        let joins = [
+            ("time", joined(races, |race| race.time)),
+            ("distance", joined(races, |race| race.distance_record)),
+        ];
+        for (line, (what, join)) in input.trim().lines().zip(joins) {
+            if join.is_none() {
+                let message = format!("expected the joined {what} to fit in 64 bits");
+                return Err(ParseError::at(input, line, message));
+            }
+        }
+        Ok(())
+    }
+}
+
+// the numbers written one after another, if there are any and they fit
+fn joined(races: &[Race], number: fn(&Race) -> u64) -> Option<u64> {
+    races
+        .iter()
+        .map(|race| number(race).to_string())
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_solution::{part1, part2};
+
+    #[test]
+    fn too_long() {
+        let input = "Time: 7 15\nDistance: 9 4000000000000000000";
+        assert_eq!(part1::<Day>(input), Ok("0".to_string()));
+        assert_eq!(
+            part2::<Day>(input).unwrap_err().to_string(),
+            "line 2, column 1: expected the joined distance to fit in 64 bits"
+        );
+    }
+}
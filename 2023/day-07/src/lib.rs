@@ -1,11 +1,10 @@
-use std::{cmp::Ordering, collections::HashMap};
+use std::{cmp::Ordering, collections::HashMap, fmt::Display};
 
-fn main() {
-    let input = include_str!("../../input.txt");
-    let answer = get_answer(input);
-    println!("answer {answer}");
-}
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
 
+// named after the puzzle's hand types
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum Kind {
     HighCard,     // where all cards' labels are distinct: 23456
@@ -18,13 +17,14 @@ enum Kind {
 }
 
 impl Kind {
-    fn from(cards: &str /* eg "T55J5" */) -> Kind {
+    // in part 2 the Js are jokers, which act as whatever card is best
+    fn from(cards: &str /* eg "T55J5" */, jokers: bool) -> Kind {
         let mut counts: HashMap<char, i32> = cards.chars().fold(HashMap::new(), |mut map, card| {
             map.entry(card).and_modify(|count| *count += 1).or_insert(1);
             map
         });
         let num_jokers = *counts.get(&'J').unwrap_or(&0);
-        if num_jokers > 0 && num_jokers < 5 {
+        if jokers && num_jokers > 0 && num_jokers < 5 {
             // for the purposes of ranking, increase the already-highest non-joker card count by the number of jokers
             let (card, count) = counts
                 .iter()
@@ -53,27 +53,31 @@ impl Kind {
             }
             return Kind::OnePair;
         }
-        return Kind::HighCard;
+        Kind::HighCard
     }
 }
 
 #[derive(Debug)]
-struct Hand<'a> {
+pub struct Hand<'a> {
     cards: &'a str, // eg "32T3K"
-    kind: Kind,
     bid: u64,
 }
 
 const CARD_ORDER: [char; 13] = [
+    'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+];
+
+// jokers are the weakest card on their own
+const JOKER_CARD_ORDER: [char; 13] = [
     'A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4', '3', '2', 'J',
 ];
 
-fn cmp_cards<'a>(a: &'a str, b: &'a str) -> Ordering {
+fn cmp_cards<'a>(a: &'a str, b: &'a str, order: &[char]) -> Ordering {
     a.chars()
         .zip(b.chars())
         .find_map(|(a, b)| {
-            match CARD_ORDER.iter().position(|c| c == &a).unwrap() as isize
-                - CARD_ORDER.iter().position(|c| c == &b).unwrap() as isize
+            match order.iter().position(|c| c == &a).unwrap() as isize
+                - order.iter().position(|c| c == &b).unwrap() as isize
             {
                 0 => None,
                 // this feels quite manual :( I'm sure there is a simpler way
@@ -86,11 +90,25 @@ fn cmp_cards<'a>(a: &'a str, b: &'a str) -> Ordering {
         .unwrap_or(Ordering::Equal)
 }
 
-fn cmp_hands<'a>(a: &Hand<'a>, b: &Hand<'a>) -> Ordering {
-    match a.kind.cmp(&b.kind) {
-        Ordering::Equal => cmp_cards(a.cards, b.cards).reverse(),
+// the total winnings, from each bid times the rank of its hand
+fn winnings(hands: &Hands, jokers: bool) -> u64 {
+    let order = if jokers {
+        &JOKER_CARD_ORDER
+    } else {
+        &CARD_ORDER
+    };
+    let mut hands = hands
+        .iter()
+        .map(|hand| (Kind::from(hand.cards, jokers), hand))
+        .collect::<Vec<_>>();
+    hands.sort_by(|(a_kind, a), (b_kind, b)| match a_kind.cmp(b_kind) {
+        Ordering::Equal => cmp_cards(a.cards, b.cards, order).reverse(),
         other => other,
-    }
+    });
+    hands
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, (_, hand))| acc + hand.bid * ((i as u64) + 1))
 }
 
 // it wasn't this simple :( Did it with the function above instead
@@ -105,32 +123,47 @@ fn cmp_hands<'a>(a: &Hand<'a>, b: &Hand<'a>) -> Ordering {
 
 type Hands<'a> = Vec<Hand<'a>>;
 
-fn parse_input(input: &str) -> Hands {
+fn parse_input(input: &str) -> Result<Hands<'_>, ParseError> {
     input
         .trim()
         .lines()
         .map(|line| {
-            let mut iter = line.split(" ");
-            let cards = iter.next().unwrap();
-            let rank = Kind::from(cards);
-            let bid: u64 = iter.next().unwrap().parse().unwrap();
-            Hand {
-                cards,
-                kind: rank,
-                bid,
+            let (cards, bid) = aoc_parse::split_once(input, line, " ")?;
+            if let Some((i, card)) = cards.char_indices().find(|(_, c)| !CARD_ORDER.contains(c)) {
+                let card = &cards[i..i + card.len_utf8()];
+                return Err(ParseError::at(
+                    input,
+                    card,
+                    format!("unknown card '{card}'"),
+                ));
             }
+            let bid: u64 = aoc_parse::parse(input, bid, "a bid")?;
+            Ok(Hand { cards, bid })
         })
         .collect()
 }
 
-fn get_answer(input: &str) -> u64 {
-    let mut hands = parse_input(input);
-    hands.sort_by(cmp_hands);
-    let total = hands
-        .iter()
-        .enumerate()
-        .fold(0, |acc, (i, hand)| acc + hand.bid * ((i as u64) + 1));
-    total
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Hands<'a>;
+
+    fn parse(input: &str) -> Result<Hands<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(hands: &Self::Parsed<'_>) -> impl Display {
+        winnings(hands, false)
+    }
+
+    fn part2(hands: &Self::Parsed<'_>) -> impl Display {
+        winnings(hands, true)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
 }
 
 #[cfg(test)]
@@ -139,26 +172,16 @@ mod tests {
 
     #[test]
     fn rank_from() {
-        assert_eq!(Kind::from("77777"), Kind::FiveOfAKind);
-        assert_eq!(Kind::from("55515"), Kind::FourOfAKind);
-        assert_eq!(Kind::from("Q1Q1Q"), Kind::FullHouse);
-        assert_eq!(Kind::from("A1AAQ"), Kind::ThreeOfAKind);
-        assert_eq!(Kind::from("11233"), Kind::TwoPair);
-        assert_eq!(Kind::from("11234"), Kind::OnePair);
-        assert_eq!(Kind::from("12345"), Kind::HighCard);
-        assert_eq!(Kind::from("T55J5"), Kind::FourOfAKind);
-        assert_eq!(Kind::from("KTJJT"), Kind::FourOfAKind);
-        assert_eq!(Kind::from("JJJ11"), Kind::FiveOfAKind);
-    }
-
-    #[test]
-    fn example() {
-        let input = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-        let answer = get_answer(input);
-        assert_eq!(answer, 5905);
+        assert_eq!(Kind::from("77777", false), Kind::FiveOfAKind);
+        assert_eq!(Kind::from("55515", false), Kind::FourOfAKind);
+        assert_eq!(Kind::from("Q1Q1Q", false), Kind::FullHouse);
+        assert_eq!(Kind::from("A1AAQ", false), Kind::ThreeOfAKind);
+        assert_eq!(Kind::from("11233", false), Kind::TwoPair);
+        assert_eq!(Kind::from("11234", false), Kind::OnePair);
+        assert_eq!(Kind::from("12345", false), Kind::HighCard);
+        assert_eq!(Kind::from("T55J5", false), Kind::ThreeOfAKind);
+        assert_eq!(Kind::from("T55J5", true), Kind::FourOfAKind);
+        assert_eq!(Kind::from("KTJJT", true), Kind::FourOfAKind);
+        assert_eq!(Kind::from("JJJ11", true), Kind::FiveOfAKind);
     }
 }
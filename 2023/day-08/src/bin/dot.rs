@@ -0,0 +1,8 @@
+use y2023_day_08::{parse_input, to_dot};
+
+// the network as Graphviz, to see the separate loops each ghost walks
+fn main() {
+    let input = &aoc_input::load_or_exit(2023, 8);
+    let (_, graph) = aoc_parse::or_exit(parse_input(input), input);
+    println!("{}", to_dot(&graph));
+}
@@ -0,0 +1,241 @@
+use aoc_dot::Dot;
+use aoc_parse::{Context, ParseError};
+use aoc_solution::Solution;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Clone, Debug)]
+pub enum Dir {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Graph<'a> {
+    names: Vec<&'a str>, // list of names
+    left: Vec<usize>,    // left move to `names` index
+    right: Vec<usize>,   // right move to `names` index
+}
+
+type Node<'a> = (&'a str, (&'a str, &'a str));
+
+// with each node's moves as indexes, failing at a move to a node that's
+// never defined
+fn map_nodes<'a>(input: &str, nodes: Vec<Node<'a>>) -> Result<Graph<'a>, ParseError> {
+    let names: Vec<&'a str> = nodes.iter().map(|(name, _)| *name).collect();
+    let indexes: HashMap<&str, usize> = names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| (*name, index))
+        .collect();
+    let index = |name: &str| {
+        indexes
+            .get(name)
+            .copied()
+            .ok_or_else(|| ParseError::at(input, name, format!("undefined node {name}")))
+    };
+    let mut left: Vec<usize> = vec![];
+    let mut right: Vec<usize> = vec![];
+    for (_, (to_left, to_right)) in nodes {
+        left.push(index(to_left)?);
+        right.push(index(to_right)?);
+    }
+    Ok(Graph { names, left, right })
+}
+
+// the directions and the network, which needn't have an AAA - the ghosts'
+// example doesn't
+pub fn parse_input(input: &str) -> Result<(Vec<Dir>, Graph<'_>), ParseError> {
+    let (dirs, nodes) = aoc_parse::split_once(input, input.trim(), "\n\n")?;
+    let dirs = aoc_parse::cells(input, dirs, |ch| match ch {
+        'L' => Some(Dir::Left),
+        'R' => Some(Dir::Right),
+        _ => None,
+    })?;
+    // AAA = (BBB, CCC)
+    let nodes = aoc_parse::lines(input, nodes, |cursor| {
+        let name = cursor.word()?;
+        cursor.tag(" = (")?;
+        let left = cursor.word()?;
+        cursor.tag(", ")?;
+        let right = cursor.word()?;
+        cursor.tag(")")?;
+        Ok((name, (left, right)))
+    })
+    .context("a node")?;
+    Ok((dirs, map_nodes(input, nodes)?))
+}
+
+// the node map, with the ghosts' starts (..A) and ends (..Z) highlighted
+pub fn to_dot(graph: &Graph) -> Dot {
+    let mut dot = Dot::digraph("network");
+    for name in &graph.names {
+        if name.ends_with('A') {
+            dot.node(name, &[("style", "filled"), ("fillcolor", "palegreen")]);
+        } else if name.ends_with('Z') {
+            dot.node(name, &[("style", "filled"), ("fillcolor", "salmon")]);
+        }
+    }
+    for (index, name) in graph.names.iter().enumerate() {
+        let (left, right) = (
+            graph.names[graph.left[index]],
+            graph.names[graph.right[index]],
+        );
+        if left == right {
+            dot.edge(name, left, &[("label", "LR")]);
+        } else {
+            dot.edge(name, left, &[("label", "L")]);
+            dot.edge(name, right, &[("label", "R")]);
+        }
+    }
+    dot
+}
+
+// how many moves it takes to get from the start to any end
+fn count_moves(
+    directions: &[Dir],
+    graph: &Graph,
+    start: usize,
+    is_end: impl Fn(&str) -> bool,
+) -> usize {
+    let mut current_index = start;
+    let mut moves = 0;
+    while !is_end(graph.names[current_index]) {
+        let transition = match directions[moves % directions.len()] {
+            Dir::Left => &graph.left,
+            Dir::Right => &graph.right,
+        };
+        current_index = transition[current_index];
+        moves += 1;
+    }
+    moves
+}
+
+// each ghost goes round its own loop, so they all line up at the lowest
+// common multiple of their moves
+fn ghost_moves(directions: &[Dir], graph: &Graph) -> usize {
+    graph
+        .names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| name.ends_with('A'))
+        .map(|(start, _)| count_moves(directions, graph, start, |name| name.ends_with('Z')))
+        .fold(1, lcm)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    // along with where AAA is, to start part 1 from, as ghost maps needn't
+    // have one
+    type Parsed<'a> = (Vec<Dir>, Graph<'a>, Option<usize>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let (directions, graph) = parse_input(input)?;
+        let start = graph.names.iter().position(|name| *name == "AAA");
+        Ok((directions, graph, start))
+    }
+
+    fn part1((directions, graph, start): &Self::Parsed<'_>) -> impl Display {
+        let start = start.expect("a checked AAA");
+        count_moves(directions, graph, start, |name| name == "ZZZ")
+    }
+
+    fn part2((directions, graph, _): &Self::Parsed<'_>) -> impl Display {
+        ghost_moves(directions, graph)
+    }
+
+    // part 1 walks from AAA to ZZZ
+    fn check(
+        input: &str,
+        (_, graph, start): &Self::Parsed<'_>,
+        part: u8,
+    ) -> Result<(), ParseError> {
+        if part != 1 {
+            return Ok(());
+        }
+        for (node, found) in [
+            ("AAA", start.is_some()),
+            ("ZZZ", graph.names.contains(&"ZZZ")),
+        ] {
+            if !found {
+                let message = format!("expected a node {node}");
+                return Err(ParseError::at(input, aoc_parse::end(input.trim()), message));
+            }
+        }
+        Ok(())
+    }
+}
+
+// following two functions stolen from https://rustp.org/number-theory/lcm/
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    if a == b {
+        return a;
+    }
+    if b > a {
+        std::mem::swap(&mut a, &mut b);
+    }
+    while b > 0 {
+        let temp = a;
+        a = b;
+        b = temp % b;
+    }
+    a
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    // LCM = a*b / gcd
+    a * (b / gcd(a, b))
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_solution::{part1, part2};
+
+    // the puzzle's example for part 2 has no AAA or ZZZ for part 1
+    #[test]
+    fn ghosts() {
+        let input = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\nXXX = (XXX, XXX)";
+        assert_eq!(part2::<Day>(input), Ok("2".to_string()));
+        assert_eq!(
+            part1::<Day>(input).unwrap_err().to_string(),
+            "line 6, column 17: expected a node AAA"
+        );
+        let input = "L\n\nAAA = (AAA, AAA)";
+        assert_eq!(
+            part1::<Day>(input).unwrap_err().to_string(),
+            "line 3, column 17: expected a node ZZZ"
+        );
+    }
+
+    #[test]
+    fn undefined() {
+        let input = "L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        assert_eq!(
+            parse_input(input).unwrap_err().to_string(),
+            "line 3, column 8: undefined node BBB"
+        );
+    }
+
+    #[test]
+    fn dot() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+XXX = (XXX, XXX)";
+        let (_, graph) = parse_input(input).unwrap();
+        let dot = to_dot(&graph).to_string();
+        assert!(dot.contains(r#""11A" [style="filled", fillcolor="palegreen"];"#));
+        assert!(dot.contains(r#""11Z" [style="filled", fillcolor="salmon"];"#));
+        assert!(dot.contains(r#""11B" -> "11Z" [label="R"];"#));
+        assert!(dot.contains(r#""XXX" -> "XXX" [label="LR"];"#));
+    }
+}
@@ -0,0 +1,75 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+fn parse_input(input: &str) -> Result<Vec<Vec<isize>>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            line.split(' ')
+                .map(|s| aoc_parse::parse(input, s, "a number"))
+                .collect()
+        })
+        .collect()
+}
+
+fn get_diffs(vec: &[isize]) -> Vec<isize> {
+    match vec.len() {
+        1 => vec![0],
+        _ => (1..vec.len())
+            .map(|n| vec.get(n).unwrap() - vec.get(n - 1).unwrap())
+            .collect(),
+    }
+}
+
+fn get_next_in_sequence(vec: &[isize]) -> isize {
+    // if all the numbers are the same...
+    let num = vec.first().unwrap();
+    if vec.iter().skip(1).all(|other| num == other) {
+        // then it's that number
+        return *num;
+    }
+
+    // else crunch another set of deltas and add the result to the last number
+    let diffs = get_diffs(vec);
+    let to_add = get_next_in_sequence(&diffs);
+    vec.last().unwrap() + to_add
+}
+
+fn get_prev_in_sequence(vec: &[isize]) -> isize {
+    // if all the numbers are the same...
+    let first = vec.first().unwrap();
+    if vec.iter().skip(1).all(|other| first == other) {
+        // then it's that number
+        return *first;
+    }
+
+    // else crunch another set of deltas and subtract the result from the first number
+    let diffs = get_diffs(vec);
+    let to_add = get_prev_in_sequence(&diffs);
+    first - to_add
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Vec<isize>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<isize>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(nums: &Self::Parsed<'_>) -> impl Display {
+        nums.iter().map(|n| get_next_in_sequence(n)).sum::<isize>()
+    }
+
+    fn part2(nums: &Self::Parsed<'_>) -> impl Display {
+        nums.iter().map(|n| get_prev_in_sequence(n)).sum::<isize>()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
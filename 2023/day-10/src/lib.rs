@@ -0,0 +1,179 @@
+use aoc_grid::{Dir, Grid, Point};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+mod part2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Pipe {
+    // NotAPipe,
+    NorthToSouth, // | vertical
+    EastToWest,   // - horizontal
+    NorthToEast,  // L
+    NorthToWest,  // J
+    SouthToWest,  // 7
+    SouthToEast,  // F
+    Start,        // S
+    Ground,       // .
+}
+
+impl Pipe {
+    fn from(ch: char) -> Option<Pipe> {
+        Some(match ch {
+            '|' => Pipe::NorthToSouth,
+            '-' => Pipe::EastToWest,
+            'L' => Pipe::NorthToEast,
+            'J' => Pipe::NorthToWest,
+            '7' => Pipe::SouthToWest,
+            'F' => Pipe::SouthToEast,
+            'S' => Pipe::Start,
+            '.' => Pipe::Ground,
+            _ => return None,
+        })
+    }
+}
+
+pub type Sketch = Grid<Pipe>;
+
+impl Display for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ch = match self {
+            Pipe::NorthToSouth => '|',
+            Pipe::EastToWest => '-',
+            Pipe::NorthToEast => 'L',
+            Pipe::NorthToWest => 'J',
+            Pipe::SouthToWest => '7',
+            Pipe::SouthToEast => 'F',
+            Pipe::Start => 'S',
+            Pipe::Ground => '.',
+        };
+        write!(f, "{ch}")
+    }
+}
+
+#[derive(Debug)]
+pub struct Move {
+    pub direction: Dir,
+    pub point: Point,
+    pub move_num: u32,
+}
+
+pub fn get_next_move(sketch: &Sketch, prev_move: &Move) -> Option<Move> {
+    let point = sketch.step(prev_move.point, prev_move.direction)?;
+    let direction = match (prev_move.direction, sketch[point]) {
+        (Dir::North, Pipe::NorthToSouth) => Dir::North,
+        (Dir::North, Pipe::SouthToEast) => Dir::East,
+        (Dir::North, Pipe::SouthToWest) => Dir::West,
+        (Dir::South, Pipe::NorthToSouth) => Dir::South,
+        (Dir::South, Pipe::NorthToEast) => Dir::East,
+        (Dir::South, Pipe::NorthToWest) => Dir::West,
+        (Dir::East, Pipe::EastToWest) => Dir::East,
+        (Dir::East, Pipe::SouthToWest) => Dir::South,
+        (Dir::East, Pipe::NorthToWest) => Dir::North,
+        (Dir::West, Pipe::EastToWest) => Dir::West,
+        (Dir::West, Pipe::SouthToEast) => Dir::South,
+        (Dir::West, Pipe::NorthToEast) => Dir::North,
+        _ => return None,
+    };
+    Some(Move {
+        point,
+        direction,
+        move_num: prev_move.move_num + 1,
+    })
+}
+
+// the loop through the start, beginning with it, and the pipe the start
+// hides, which is whichever one joins the two pipes leading into it
+fn find_loop(sketch: &Sketch, start: Point) -> Option<(Pipe, Vec<Point>)> {
+    let exits = Dir::ALL
+        .into_iter()
+        .filter(|&direction| {
+            let first = Move {
+                point: start,
+                direction,
+                move_num: 0,
+            };
+            get_next_move(sketch, &first).is_some()
+        })
+        .collect::<Vec<_>>();
+    let start_pipe = match exits[..] {
+        [Dir::North, Dir::East] => Pipe::NorthToEast,
+        [Dir::North, Dir::South] => Pipe::NorthToSouth,
+        [Dir::North, Dir::West] => Pipe::NorthToWest,
+        [Dir::East, Dir::South] => Pipe::SouthToEast,
+        [Dir::East, Dir::West] => Pipe::EastToWest,
+        [Dir::South, Dir::West] => Pipe::SouthToWest,
+        _ => return None,
+    };
+
+    let mut points = vec![start];
+    let mut current = Move {
+        point: start,
+        direction: exits[0],
+        move_num: 0,
+    };
+    loop {
+        if sketch.step(current.point, current.direction)? == start {
+            return Some((start_pipe, points));
+        }
+        current = get_next_move(sketch, &current)?;
+        points.push(current.point);
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    // with the start swapped for the pipe it hides, and the loop through it
+    type Parsed<'a> = (Sketch, Vec<Point>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut sketch = Grid::try_parse(input, Pipe::from)?;
+        let Some(start) = sketch.position(|pipe| *pipe == Pipe::Start) else {
+            let message = "expected a start S";
+            return Err(ParseError::at(input, aoc_parse::end(input.trim()), message));
+        };
+        let Some((start_pipe, points)) = find_loop(&sketch, start) else {
+            let line = input.trim().lines().nth(start.y).expect("the start's line");
+            let message = "expected the start to be on a loop";
+            return Err(ParseError::at(input, &line[start.x..=start.x], message));
+        };
+        sketch[start] = start_pipe;
+        Ok((sketch, points))
+    }
+
+    // the furthest point along the loop from the start
+    fn part1((_, points): &Self::Parsed<'_>) -> impl Display {
+        points.len() / 2
+    }
+
+    // how many tiles the loop encloses
+    fn part2((sketch, points): &Self::Parsed<'_>) -> impl Display {
+        part2::count_holes(sketch, points)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid() {
+        let error = |input| Day::parse(input).map(|_| ()).unwrap_err().to_string();
+        assert_eq!(error("F7\nL-"), "line 2, column 3: expected a start S");
+        assert_eq!(
+            error("F7.\nLJS"),
+            "line 2, column 3: expected the start to be on a loop"
+        );
+        assert_eq!(
+            error("S7\nL-"),
+            "line 1, column 1: expected the start to be on a loop"
+        );
+    }
+}
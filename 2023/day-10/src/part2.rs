@@ -0,0 +1,201 @@
+use crate::{Pipe, Sketch};
+use aoc_grid::{Dir, Grid, Point};
+
+pub fn count_holes(sketch: &Sketch, points: &[Point]) -> usize {
+    // next reduce the data to just the loop, where the start is already the pipe it hides
+    let sketch: Sketch = Grid::new(
+        sketch.width(),
+        sketch.height(),
+        sketch
+            .iter()
+            .map(|(point, pipe)| {
+                if points.contains(&point) {
+                    *pipe
+                } else {
+                    Pipe::Ground
+                }
+            })
+            .collect(),
+    );
+
+    // check every tile in the grid from 1..len-1 (we can ignore the outmost tiles because they must be pipe or nothing)
+    // if it's inside the loop it will have an odd number of intersections in all directions
+
+    let mut holes: usize = 0;
+    for (point, _) in sketch.iter().filter(|(_, pipe)| pipe == &&Pipe::Ground) {
+        let Point { x, y } = point;
+        // println!("checking ground {:?} {},{}", pipe, x, y);
+        // check in all directions to edge of the, first left
+        let mut last_bend = None;
+        let mut num_intersections = 0;
+        let mut offset = 1;
+        while let Some(test_x) = x.checked_sub(offset) {
+            offset += 1;
+            let test_pipe = sketch[Point::new(test_x, y)];
+            // println!("checking {:?} at left {},{}", test_pipe, test_x, y);
+            match (test_pipe, &last_bend) {
+                (Pipe::NorthToSouth, None) => {
+                    // println!("left intersection");
+                    num_intersections += 1;
+                }
+                (Pipe::SouthToEast | Pipe::SouthToWest, None) => {
+                    last_bend = Some(Dir::South);
+                }
+                (Pipe::NorthToEast | Pipe::NorthToWest, None) => {
+                    last_bend = Some(Dir::North);
+                }
+                (Pipe::SouthToEast | Pipe::SouthToWest, Some(Dir::South)) => {
+                    last_bend = None;
+                }
+                (Pipe::NorthToEast | Pipe::NorthToWest, Some(Dir::North)) => {
+                    last_bend = None;
+                }
+                (Pipe::NorthToEast | Pipe::NorthToWest, Some(Dir::South)) => {
+                    num_intersections += 1;
+                    last_bend = None;
+                }
+                (Pipe::SouthToEast | Pipe::SouthToWest, Some(Dir::North)) => {
+                    num_intersections += 1;
+                    last_bend = None;
+                }
+                _ => { /* ignore */ }
+            }
+        }
+        if num_intersections % 2 != 1 {
+            continue;
+        }
+
+        // then right
+        let mut last_bend = None;
+        let mut num_intersections = 0;
+        let mut offset = 1;
+        loop {
+            let test_x = x + offset;
+            if test_x == sketch.width() {
+                break;
+            }
+            offset += 1;
+            let test_pipe = sketch[Point::new(test_x, y)];
+            // println!("checking {:?} at left {},{}", test_pipe, test_x, y);
+            // this is exactly the same as above :-( - refactor I have time
+            match (test_pipe, &last_bend) {
+                (Pipe::NorthToSouth, None) => {
+                    // println!("right intersection");
+                    num_intersections += 1;
+                }
+                (Pipe::SouthToEast | Pipe::SouthToWest, None) => {
+                    last_bend = Some(Dir::South);
+                }
+                (Pipe::NorthToEast | Pipe::NorthToWest, None) => {
+                    last_bend = Some(Dir::North);
+                }
+                (Pipe::SouthToEast | Pipe::SouthToWest, Some(Dir::South)) => {
+                    last_bend = None;
+                }
+                (Pipe::NorthToEast | Pipe::NorthToWest, Some(Dir::North)) => {
+                    last_bend = None;
+                }
+                (Pipe::NorthToEast | Pipe::NorthToWest, Some(Dir::South)) => {
+                    num_intersections += 1;
+                    last_bend = None;
+                }
+                (Pipe::SouthToEast | Pipe::SouthToWest, Some(Dir::North)) => {
+                    num_intersections += 1;
+                    last_bend = None;
+                }
+                _ => { /* ignore */ }
+            }
+        }
+        if num_intersections % 2 != 1 {
+            continue;
+        }
+        // println!("inside loop {},{}", x, y);
+
+        // then north
+        let mut last_bend = None;
+        let mut num_intersections = 0;
+        let mut offset = 1;
+        while let Some(test_y) = y.checked_sub(offset) {
+            offset += 1;
+            let test_pipe = sketch[Point::new(x, test_y)];
+            // println!("checking {:?} at left {},{}", test_pipe, x, test_y);
+            match (test_pipe, &last_bend) {
+                (Pipe::EastToWest, None) => {
+                    // println!("north intersection");
+                    num_intersections += 1;
+                }
+                (Pipe::SouthToEast | Pipe::NorthToEast, None) => {
+                    last_bend = Some(Dir::East);
+                }
+                (Pipe::SouthToWest | Pipe::NorthToWest, None) => {
+                    last_bend = Some(Dir::West);
+                }
+                (Pipe::SouthToEast | Pipe::NorthToEast, Some(Dir::East)) => {
+                    last_bend = None;
+                }
+                (Pipe::SouthToWest | Pipe::NorthToWest, Some(Dir::West)) => {
+                    last_bend = None;
+                }
+                (Pipe::SouthToWest | Pipe::NorthToWest, Some(Dir::East)) => {
+                    num_intersections += 1;
+                    last_bend = None;
+                }
+                (Pipe::SouthToEast | Pipe::NorthToEast, Some(Dir::West)) => {
+                    num_intersections += 1;
+                    last_bend = None;
+                }
+                _ => { /* ignore */ }
+            }
+        }
+        if num_intersections % 2 != 1 {
+            continue;
+        }
+
+        // then south
+        let mut last_bend = None;
+        let mut num_intersections = 0;
+        let mut offset = 1;
+        loop {
+            let test_y = y + offset;
+            if test_y == sketch.height() {
+                break;
+            }
+            offset += 1;
+            let test_pipe = sketch[Point::new(x, test_y)];
+            // this is exactly the same as above :-( - refactor I have time
+            match (test_pipe, &last_bend) {
+                (Pipe::EastToWest, None) => {
+                    // println!("north intersection");
+                    num_intersections += 1;
+                }
+                (Pipe::SouthToEast | Pipe::NorthToEast, None) => {
+                    last_bend = Some(Dir::East);
+                }
+                (Pipe::SouthToWest | Pipe::NorthToWest, None) => {
+                    last_bend = Some(Dir::West);
+                }
+                (Pipe::SouthToEast | Pipe::NorthToEast, Some(Dir::East)) => {
+                    last_bend = None;
+                }
+                (Pipe::SouthToWest | Pipe::NorthToWest, Some(Dir::West)) => {
+                    last_bend = None;
+                }
+                (Pipe::SouthToWest | Pipe::NorthToWest, Some(Dir::East)) => {
+                    num_intersections += 1;
+                    last_bend = None;
+                }
+                (Pipe::SouthToEast | Pipe::NorthToEast, Some(Dir::West)) => {
+                    num_intersections += 1;
+                    last_bend = None;
+                }
+                _ => { /* ignore */ }
+            }
+        }
+        if num_intersections % 2 != 1 {
+            continue;
+        }
+
+        holes += 1;
+    }
+    holes
+}
@@ -1,39 +1,34 @@
-fn main() {
-    let input = include_str!("../../input.txt");
-    let answer = get_answer(input, 1_000_000);
-    println!("answer {answer}");
-}
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-enum Item {
+pub enum Item {
     Nothing,
     Galaxy,
 }
 
-fn parse_input(input: &str) -> Vec<Vec<Item>> {
+fn parse_input(input: &str) -> Result<Vec<Vec<Item>>, ParseError> {
     input
         .trim()
         .lines()
         .map(|line| {
-            line.chars()
-                .map(|ch| match ch {
-                    '.' => Item::Nothing,
-                    '#' => Item::Galaxy,
-                    _ => panic!("Unexpected item {ch}"),
-                })
-                .collect()
+            aoc_parse::cells(input, line, |ch| match ch {
+                '.' => Some(Item::Nothing),
+                '#' => Some(Item::Galaxy),
+                _ => None,
+            })
         })
         .collect()
 }
 
 fn expand_galaxies(
-    galaxies: &Vec<(usize, usize)>,
-    initial_map: &Vec<Vec<Item>>,
+    galaxies: &[(usize, usize)],
+    initial_map: &[Vec<Item>],
     expansion: usize,
 ) -> Vec<(usize, usize)> {
     // find rows and columns with only nothing
     let empty_rows: Vec<usize> = (0..initial_map.len())
-        .into_iter()
         .filter(|y| {
             initial_map
                 .get(*y)
@@ -42,9 +37,8 @@ fn expand_galaxies(
                 .all(|item| item == &Item::Nothing)
         })
         .collect();
-    let initial_num_cols = initial_map.get(0).unwrap().len();
+    let initial_num_cols = initial_map.first().unwrap().len();
     let empty_cols: Vec<usize> = (0..initial_num_cols)
-        .into_iter()
         .filter(|x| {
             initial_map
                 .iter()
@@ -54,9 +48,9 @@ fn expand_galaxies(
     // println!("empty rows {:?}, empty_cols {:?}", empty_rows, empty_cols);
 
     // helper to expand a given index
-    fn expand(empties: &Vec<usize>, initial_index: &usize, expansion: &usize) -> usize {
+    fn expand(empties: &[usize], initial_index: &usize, expansion: &usize) -> usize {
         empties.iter().fold(*initial_index, |acc, index| {
-            if index < &initial_index {
+            if index < initial_index {
                 acc + expansion - 1
             } else {
                 acc
@@ -87,8 +81,8 @@ fn shortest_path(a: &(usize /* x */, usize /* y */), b: &(usize /* x */, usize /
     (diagonals + remainder) as usize
 }
 
-fn get_answer(input: &str, expansion: usize) -> usize {
-    let initial_map = parse_input(input);
+// every empty row and column is really `expansion` of them
+pub fn sum_of_distances(initial_map: &[Vec<Item>], expansion: usize) -> usize {
     let galaxies: Vec<(usize, usize)> = initial_map
         .iter()
         .enumerate()
@@ -99,15 +93,10 @@ fn get_answer(input: &str, expansion: usize) -> usize {
         })
         .collect();
     // println!("found {} galaxies {:?}", galaxies.len(), galaxies);
-    let galaxies = expand_galaxies(&galaxies, &initial_map, expansion);
+    let galaxies = expand_galaxies(&galaxies, initial_map, expansion);
     // println!("expanded {} galaxies {:?}", galaxies.len(), galaxies);
     let pairs: Vec<(usize, usize)> = (0..galaxies.len())
-        .into_iter()
-        .flat_map(|index| {
-            (index + 1..galaxies.len())
-                .into_iter()
-                .map(move |other| (index, other))
-        })
+        .flat_map(|index| (index + 1..galaxies.len()).map(move |other| (index, other)))
         .collect();
     // println!("{} galaxy pairs {:?}", pairs.len(), pairs);
     pairs
@@ -122,55 +111,37 @@ fn get_answer(input: &str, expansion: usize) -> usize {
         .sum()
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::get_answer;
+pub struct Day;
+
+impl Solution for Day {
+    // the map and how much the empty space really expanded in part 2
+    type Parsed<'a> = (Vec<Vec<Item>>, usize);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok((parse_input(input)?, 1_000_000))
+    }
 
-    #[test]
-    fn example_2() {
-        let input = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-        let answer = get_answer(input, 2);
-        assert_eq!(answer, 374);
+    fn part1((map, _): &Self::Parsed<'_>) -> impl Display {
+        sum_of_distances(map, 2)
     }
 
-    #[test]
-    fn example_10() {
-        let input = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-        let answer = get_answer(input, 10);
-        assert_eq!(answer, 1030);
+    // the galaxies are much older than they looked
+    fn part2((map, expansion): &Self::Parsed<'_>) -> impl Display {
+        sum_of_distances(map, *expansion)
     }
 
-    #[test]
-    fn example_100() {
-        let input = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-        let answer = get_answer(input, 100);
-        assert_eq!(answer, 8410);
+    fn set((_, expansion): &mut Self::Parsed<'_>, name: &str, value: u64) -> bool {
+        match (name, usize::try_from(value)) {
+            ("expansion", Ok(value)) => {
+                *expansion = value;
+                true
+            }
+            _ => false,
+        }
     }
 }
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
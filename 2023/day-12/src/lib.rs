@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use itertools::Itertools;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Record {
+    springs: String,
+    checksum: Vec<usize>,
+}
+
+impl Record {
+    // the record as it really was, five times over
+    fn unfold(&self) -> Record {
+        Record {
+            springs: std::iter::repeat_n(&self.springs, 5).join("?"),
+            checksum: self.checksum.repeat(5),
+        }
+    }
+
+    fn arrangements(&self) -> usize {
+        count(&self.springs, &self.checksum, &mut HashMap::new())
+    }
+}
+
+type Records = Vec<Record>;
+
+// ???.### 1,1,3
+fn parse_record(input: &str, line: &str) -> Result<Record, ParseError> {
+    let (springs, checksum) = aoc_parse::split_once(input, line, " ")?;
+    aoc_parse::cells(input, springs, |ch| {
+        matches!(ch, '.' | '#' | '?').then_some(ch)
+    })?;
+
+    let checksum: Vec<usize> = checksum
+        .split(',')
+        .map(|s| aoc_parse::parse(input, s, "a group size"))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Record {
+        springs: springs.to_string(),
+        checksum,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Records, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| parse_record(input, line))
+        .collect()
+}
+
+fn count<'a>(
+    cfg: &'a str,
+    nums: &'a [usize],
+    cache: &mut HashMap<(&'a str, &'a [usize]), usize>,
+) -> usize {
+    if cfg.is_empty() {
+        // println!("empty cfg {}, nums {:?}", cfg, nums);
+        return if nums.is_empty() { 1 } else { 0 };
+    }
+
+    if nums.is_empty() {
+        // println!("empty nums {:?}, cfg {}", nums, cfg);
+        return if cfg.contains('#') { 0 } else { 1 };
+    }
+
+    let key = (cfg, nums);
+    if cache.contains_key(&key) {
+        return *cache.get(&key).unwrap();
+    }
+
+    let mut result = 0;
+
+    // if we are at a working or potentially working spring...
+    let first_char = cfg.chars().next().unwrap();
+    if first_char == '.' || first_char == '?' {
+        // skip the spring and recurse with the same number of damaged numbers
+        // println!("get count for working");
+        result += count(&cfg[1..], nums, cache);
+    }
+
+    // if we are at a damaged or potentially damaged spring...
+    if first_char == '#' || first_char == '?' {
+        // and we have at least enough input left to satisfy the next damaged-group number
+        if nums[0] <= cfg.len()
+            // and the next nums[0] input doesn't contain any working springs
+            && !cfg[..nums[0]].contains('.')
+            // and it's all the remaining input or there is something else beyond it (a working machine . or ?)
+            && (nums[0] == cfg.len() || cfg.chars().nth(nums[0]) != Some('#'))
+        {
+            // skip the length of the next damaged-group number and recurse without that number
+            let chop_from = (nums[0] + 1).min(cfg.len());
+            result += count(&cfg[chop_from..], &nums[1..], cache)
+        }
+    }
+
+    cache.insert(key, result);
+    result
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Records;
+
+    fn parse(input: &str) -> Result<Records, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(records: &Self::Parsed<'_>) -> impl Display {
+        records.iter().map(Record::arrangements).sum::<usize>()
+    }
+
+    fn part2(records: &Self::Parsed<'_>) -> impl Display {
+        records
+            .iter()
+            .map(|record| record.unfold().arrangements())
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,103 @@
+use aoc_grid::Grid;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+fn count_col_mirror_smudges(grid: &Grid<char>, x1: usize, x2: usize) -> usize {
+    grid.column(x1)
+        .zip(grid.column(x2))
+        .filter(|(a, b)| a != b)
+        .count()
+}
+
+fn count_row_mirror_smudges(grid: &Grid<char>, y1: usize, y2: usize) -> usize {
+    grid.row(y1)
+        .iter()
+        .zip(grid.row(y2))
+        .filter(|(a, b)| a != b)
+        .count()
+}
+
+type Pairs = Vec<(usize /* a */, usize /* b */)>;
+
+type MirrorPairs = (usize /* mirror index */, Pairs);
+
+fn pairs_iter(len: usize) -> impl Iterator<Item = MirrorPairs> {
+    (0..len - 1).map(move |i| {
+        (
+            i,
+            (0..((i + 1).min(len - i - 1)))
+                .map(move |j| (i - j, i + j + 1))
+                .collect(),
+        )
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+    aoc_parse::blocks(input, input, |block| {
+        Grid::try_parse(block, |ch| matches!(ch, '#' | '.').then_some(ch))
+    })
+}
+
+// the summary of each grid's mirror line, where the two sides differ by
+// exactly `smudges` cells
+fn summarize(grids: &[Grid<char>], smudges: usize) -> usize {
+    grids
+        .iter()
+        .map(|grid| {
+            // compare every increasingly wider pair of rows at each row-index
+            if let Some(mirror_row) = pairs_iter(grid.height()).find_map(|(row_index, pairs)| {
+                let mut num_smudges = 0;
+                for (a, b) in pairs {
+                    num_smudges += count_row_mirror_smudges(grid, a, b);
+                    if num_smudges > smudges {
+                        return None;
+                    }
+                }
+                (num_smudges == smudges).then_some(row_index + 1)
+            }) {
+                return mirror_row * 100;
+            }
+
+            // compare every increasingly wider pair of columns at each col-index
+            if let Some(mirror_col) = pairs_iter(grid.width()).find_map(|(col_index, pairs)| {
+                let mut num_smudges = 0;
+                for (a, b) in pairs {
+                    num_smudges += count_col_mirror_smudges(grid, a, b);
+                    if num_smudges > smudges {
+                        return None;
+                    }
+                }
+                (num_smudges == smudges).then_some(col_index + 1)
+            }) {
+                return mirror_col;
+            }
+
+            panic!("did not find a mirror row or col for grid {:?}", grid);
+        })
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Grid<char>>;
+
+    fn parse(input: &str) -> Result<Vec<Grid<char>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grids: &Self::Parsed<'_>) -> impl Display {
+        summarize(grids, 0)
+    }
+
+    // every mirror has exactly one smudge on it
+    fn part2(grids: &Self::Parsed<'_>) -> impl Display {
+        summarize(grids, 1)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
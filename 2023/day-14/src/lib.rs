@@ -0,0 +1,193 @@
+use aoc_cycle::nth_state;
+use aoc_grid::{Dir, Grid, Point};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+fn move_rock(grid: &mut Grid<char>, from: Point, to: Point) {
+    assert_eq!(grid[from], 'O');
+    assert_eq!(grid[to], '.');
+    grid[from] = '.';
+    grid[to] = 'O';
+}
+
+fn tilt(grid: &mut Grid<char>, direction: Dir) {
+    // println!("tilt grid direction {:?}\n{}\n", direction, grid);
+
+    let (i_max, j_max, inc_i, inc_j) = match direction {
+        Dir::North => (grid.width(), grid.height(), true, true),
+        Dir::West => (grid.height(), grid.width(), true, true),
+        Dir::South => (grid.width(), grid.height(), true, false),
+        Dir::East => (grid.height(), grid.width(), true, false),
+    };
+
+    for i in 0..i_max {
+        let i = if inc_i { i } else { i_max - 1 - i };
+        // println!("checking i {i}");
+        let mut edge_index = if inc_j { 0 } else { j_max - 1 };
+        for j in 0..j_max {
+            let j = if inc_j { j } else { j_max - 1 - j };
+            // find the next rock
+            let (from, to) = match direction {
+                Dir::North | Dir::South => (Point::new(i, j), Point::new(i, edge_index)),
+                Dir::West | Dir::East => (Point::new(j, i), Point::new(edge_index, i)),
+            };
+            // println!("checking i,j {i},{j}, from {from:?}, edge index {edge_index}, inc_j {inc_j}");
+            let ch = grid[from];
+            if ch == '#' {
+                // println!("found cube rock at {j}");
+                edge_index = if inc_j { j + 1 } else { j.saturating_sub(1) };
+            } else if ch == 'O' {
+                if j != edge_index {
+                    // println!("moving round rock at i {i}, j {j}, to {edge_index} (from {from:?} to {to:?}");
+                    move_rock(grid, from, to);
+                }
+                edge_index = if inc_j {
+                    edge_index + 1
+                } else {
+                    edge_index.saturating_sub(1)
+                };
+            }
+        }
+    }
+    // println!("after tilt grid is\n{}\n", grid);
+}
+
+fn spin_cycle(grid: &mut Grid<char>) {
+    tilt(grid, Dir::North);
+    tilt(grid, Dir::West);
+    tilt(grid, Dir::South);
+    tilt(grid, Dir::East);
+}
+
+fn get_weight_on_north_edge(grid: &Grid<char>) -> usize {
+    grid.iter()
+        .filter(|(_, ch)| **ch == 'O')
+        .map(|(point, _)| grid.height() - point.y)
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::try_parse(input, |ch| matches!(ch, 'O' | '#' | '.').then_some(ch))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> impl Display {
+        let mut grid = grid.clone();
+        tilt(&mut grid, Dir::North);
+        get_weight_on_north_edge(&grid)
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> impl Display {
+        // the rocks soon settle into a loop, so most of the spins can be skipped
+        let grid = nth_state(grid.clone(), 1_000_000_000, |grid| {
+            let mut grid = grid.clone();
+            spin_cycle(&mut grid);
+            grid
+        });
+        get_weight_on_north_edge(&grid)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn spin_1() {
+        let mut grid = parse_input(EXAMPLE).unwrap();
+        spin_cycle(&mut grid);
+        assert_eq!(
+            format!("{}", grid),
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#...."
+        );
+    }
+
+    #[test]
+    fn spin_2() {
+        let mut grid = parse_input(EXAMPLE).unwrap();
+        spin_cycle(&mut grid);
+        spin_cycle(&mut grid);
+        assert_eq!(
+            format!("{}", grid),
+            ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O"
+        );
+    }
+
+    #[test]
+    fn spin_3() {
+        let mut grid = parse_input(EXAMPLE).unwrap();
+        spin_cycle(&mut grid);
+        spin_cycle(&mut grid);
+        spin_cycle(&mut grid);
+        assert_eq!(
+            format!("{}", grid),
+            ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O"
+        );
+    }
+
+    #[test]
+    fn tilt_each_way() {
+        let input = "..\n.O";
+        let mut grid = parse_input(input).unwrap();
+        tilt(&mut grid, Dir::North);
+        assert_eq!(format!("{}", grid), ".O\n..");
+        tilt(&mut grid, Dir::West);
+        assert_eq!(format!("{}", grid), "O.\n..");
+        tilt(&mut grid, Dir::South);
+        assert_eq!(format!("{}", grid), "..\nO.");
+        tilt(&mut grid, Dir::East);
+        assert_eq!(format!("{}", grid), "..\n.O");
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+fn hash(s: &str) -> usize {
+    let mut h = 0;
+    for c in s.chars() {
+        // Determine the ASCII code for the current character of the string.
+        let code = c as usize;
+        // Increase the current value by the ASCII code you just determined.
+        h += code;
+        // Set the current value to itself multiplied by 17.
+        h *= 17;
+        // Set the current value to the remainder of dividing itself by 256.
+        h %= 256;
+    }
+    h
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction<'a> {
+    Remove(&'a str),
+    Replace(&'a str, u32),
+}
+
+impl Instruction<'_> {
+    fn get_box(&self) -> usize {
+        match self {
+            Instruction::Remove(label) => hash(label),
+            Instruction::Replace(label, _) => hash(label),
+        }
+    }
+}
+
+// part 1 hashes the steps as written, part 2 follows what they say
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step<'a> {
+    text: &'a str,
+    instruction: Instruction<'a>,
+}
+
+type Lens<'a> = (&'a str, u32);
+
+type Lenses<'a> = Vec<Lens<'a>>;
+
+fn replace_lens<'a>(lenses: &mut Lenses<'a>, label: &str, with_lens: Option<Lens<'a>>) {
+    if let Some(index) = lenses
+        .iter()
+        .position(|(lens_label, _)| *lens_label == label)
+    {
+        lenses.remove(index);
+        if let Some(lens) = with_lens {
+            lenses.insert(index, lens);
+        }
+    } else if let Some(lens) = with_lens {
+        lenses.push(lens);
+    }
+}
+
+fn parse_step<'a>(input: &str, text: &'a str) -> Result<Step<'a>, ParseError> {
+    let instruction = if let Some(label) = text.strip_suffix('-') {
+        Instruction::Remove(label)
+    } else if let Some((label, focal_length)) = text.split_once('=') {
+        Instruction::Replace(
+            label,
+            aoc_parse::parse(input, focal_length, "a focal length")?,
+        )
+    } else {
+        return Err(ParseError::at(input, text, "expected a label then - or ="));
+    };
+    Ok(Step { text, instruction })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|text| parse_step(input, text))
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Step<'a>>;
+
+    fn parse(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(steps: &Self::Parsed<'_>) -> impl Display {
+        steps.iter().map(|step| hash(step.text)).sum::<usize>()
+    }
+
+    fn part2(steps: &Self::Parsed<'_>) -> impl Display {
+        let mut boxes: HashMap<usize, Lenses> = HashMap::new();
+        steps.iter().for_each(|Step { instruction, .. }| {
+            let lenses = boxes.entry(instruction.get_box()).or_default();
+            match instruction {
+                Instruction::Remove(label) => {
+                    replace_lens(lenses, label, None);
+                }
+                Instruction::Replace(label, focal_length) => {
+                    replace_lens(lenses, label, Some((label, *focal_length)));
+                }
+            }
+        });
+
+        boxes
+            .iter()
+            .flat_map(|(b, lenses)| {
+                lenses
+                    .iter()
+                    .enumerate()
+                    .map(move |(l, lens)| (b + 1) * (l + 1) * (lens.1 as usize))
+            })
+            .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_hash() {
+        assert_eq!(hash("HASH"), 52);
+    }
+
+    #[test]
+    fn invalid() {
+        let error = parse_input("rn=1,cm").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected a label then - or ="
+        );
+    }
+}
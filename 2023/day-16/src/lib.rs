@@ -0,0 +1,217 @@
+use aoc_grid::{Dir, Grid, Point};
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,    // .
+    Rising,   // /
+    Falling,  // \
+    Vertical, // |
+    Sideways, // -
+}
+
+fn parse_input(input: &str) -> Result<Grid<Tile>, ParseError> {
+    let grid = Grid::try_parse(input, |ch| {
+        Some(match ch {
+            '.' => Tile::Empty,
+            '/' => Tile::Rising,
+            '\\' => Tile::Falling,
+            '|' => Tile::Vertical,
+            '-' => Tile::Sideways,
+            _ => return None,
+        })
+    })?;
+    // the beam starts in the top left corner
+    if grid.width() == 0 {
+        return Err(ParseError::at(
+            input,
+            aoc_parse::end(input),
+            "expected a grid",
+        ));
+    }
+    Ok(grid)
+}
+
+fn count_energized(grid: &Grid<Tile>, start: (Point, Dir)) -> usize {
+    let mut path = vec![];
+    let mut seen = HashSet::new();
+    let mut points = HashSet::new(); // better to use itertools to unique the set of points in `seen`
+    let mut queue = vec![start];
+    while !queue.is_empty() {
+        let current = queue.remove(queue.len() - 1);
+        seen.insert(current);
+        points.insert(current.0);
+        let (point, direction) = current;
+        path.push((point, direction));
+        // println!(
+        //     "at {:?} going {:?} remaining queue len {}",
+        //     &point,
+        //     &direction,
+        //     queue.len()
+        // );
+
+        let tile = grid[point];
+        let next_directions = match direction {
+            Dir::East => match tile {
+                Tile::Sideways | Tile::Empty => vec![Dir::East],
+                Tile::Falling => vec![Dir::South],
+                Tile::Rising => vec![Dir::North],
+                Tile::Vertical => vec![Dir::North, Dir::South],
+            },
+            Dir::West => match tile {
+                Tile::Sideways | Tile::Empty => vec![Dir::West],
+                Tile::Falling => vec![Dir::North],
+                Tile::Rising => vec![Dir::South],
+                Tile::Vertical => vec![Dir::North, Dir::South],
+            },
+            Dir::North => match tile {
+                Tile::Vertical | Tile::Empty => vec![Dir::North],
+                Tile::Falling => vec![Dir::West],
+                Tile::Rising => vec![Dir::East],
+                Tile::Sideways => vec![Dir::East, Dir::West],
+            },
+            Dir::South => match tile {
+                Tile::Vertical | Tile::Empty => vec![Dir::South],
+                Tile::Falling => vec![Dir::East],
+                Tile::Rising => vec![Dir::West],
+                Tile::Sideways => vec![Dir::East, Dir::West],
+            },
+        };
+
+        for direction in next_directions {
+            if let Some(point) = grid.step(point, direction) {
+                let next = (point, direction);
+                if !seen.contains(&next) {
+                    queue.push(next);
+                }
+            }
+        }
+    }
+
+    // for p in 0..path.len() {
+    //     println!("move {p}");
+    //     for y in 0..grid.height() {
+    //         for x in 0..grid.width() {
+    //             // let ch = grid[Point { x, y }];
+    //             // match ch {
+    //             //     '.' => {
+    //             //         let point = Point { x, y };
+    //             //         let path_dirs: Vec<Dir> = path[0..(p + 1)]
+    //             //             .iter()
+    //             //             .filter_map(|(p, dir)| (p == &point).then_some(*dir))
+    //             //             .collect();
+    //             //         let ch = match path_dirs.len() {
+    //             //             0 => grid[point],
+    //             //             1 => match path_dirs.iter().next().unwrap() {
+    //             //                 Dir::East => '>',
+    //             //                 Dir::West => '<',
+    //             //                 Dir::North => '^',
+    //             //                 Dir::South => 'v',
+    //             //             },
+    //             //             _ => path_dirs.len().to_string().chars().next().unwrap(),
+    //             //         };
+    //             //         print!("{}", ch);
+    //             //     }
+    //             //     _ => print!("{}", ch),
+    //             // }
+    //             if path.iter().find(|it| it.0 == Point { x, y }).is_some() {
+    //                 print!("#");
+    //             } else {
+    //                 let ch = grid[Point { x, y }];
+    //                 print!("{}", ch);
+    //             }
+    //         }
+    //         println!();
+    //     }
+    // }
+
+    // for p in path {
+    //     println!("path {:?}", p);
+    // }
+
+    points.len()
+}
+
+fn most_energized(grid: &Grid<Tile>) -> usize {
+    // too lazy to do this with iterators... if I had more time I might
+    // so, first east for y = 0, x = 0..col_len
+    let mut max = 0;
+    for y in 0..grid.height() {
+        max = count_energized(grid, (Point { x: 0, y }, Dir::East)).max(max);
+    }
+
+    // next south for y = 0, x = 0..col_len
+    for x in 0..grid.width() {
+        max = count_energized(grid, (Point { x, y: 0 }, Dir::South)).max(max);
+    }
+
+    // next west for y = 0..row_len - 1, x = col_len - 1
+    for y in 0..grid.height() {
+        max = count_energized(
+            grid,
+            (
+                Point {
+                    x: grid.width() - 1,
+                    y,
+                },
+                Dir::West,
+            ),
+        )
+        .max(max);
+    }
+
+    // finally north for y = row_len - 1, x = 0..col_len
+    for x in 0..grid.width() {
+        max = count_energized(
+            grid,
+            (
+                Point {
+                    x,
+                    y: grid.height() - 1,
+                },
+                Dir::North,
+            ),
+        )
+        .max(max);
+    }
+
+    max
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Grid<Tile>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> impl Display {
+        count_energized(grid, (Point { x: 0, y: 0 }, Dir::East))
+    }
+
+    // the beam can come in from any edge
+    fn part2(grid: &Self::Parsed<'_>) -> impl Display {
+        most_energized(grid)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let error = parse_input("\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a grid");
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_grid::{Dir, Grid, Point};
+use aoc_parse::ParseError;
+use aoc_search::astar;
+use aoc_solution::Solution;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+fn parse_input(input: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::try_parse(input, |c| {
+        c.to_digit(10).map(|heat_loss| heat_loss as usize)
+    })
+}
+
+// each of the blocks reached by moving a run of blocks in a straight line, with the heat lost
+fn runs(
+    grid: &Grid<usize>,
+    point: Point,
+    dir: Dir,
+    run: &RangeInclusive<usize>,
+) -> Vec<((Point, Dir), usize)> {
+    let mut runs = vec![];
+    let mut current = point;
+    let mut heat_loss = 0;
+    for steps in 1..=*run.end() {
+        let Some(next) = grid.step(current, dir) else {
+            break;
+        };
+        current = next;
+        heat_loss += grid[current];
+        if run.contains(&steps) {
+            runs.push(((current, dir), heat_loss));
+        }
+    }
+    runs
+}
+
+fn shortest_path(grid: &Grid<usize>, run: RangeInclusive<usize>) -> usize {
+    let start = Point::new(0, 0);
+    let end = Point::new(grid.width() - 1, grid.height() - 1);
+
+    // crucibles move a whole run of blocks before turning left or right, and
+    // need a whole run to stop at the end too
+    let path = astar(
+        (start, Dir::East),
+        |&(point, dir)| {
+            let turns = if point == start {
+                // nothing has been entered yet, so go either way
+                [Dir::East, Dir::South]
+            } else {
+                [dir.turn_left(), dir.turn_right()]
+            };
+            turns
+                .into_iter()
+                .flat_map(|dir| runs(grid, point, dir, &run))
+                .collect::<Vec<_>>()
+        },
+        // every block loses at least 1 heat, so this never overestimates
+        |(point, _)| point.manhattan_distance(end),
+        |(point, _)| *point == end,
+    )
+    .expect("a path to the end");
+
+    path.cost
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Grid<usize>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> impl Display {
+        shortest_path(grid, 1..=3)
+    }
+
+    // ultra crucibles move at least 4 and at most 10 blocks at a time
+    fn part2(grid: &Self::Parsed<'_>) -> impl Display {
+        shortest_path(grid, 4..=10)
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,152 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Instruction {
+    direction: Direction,
+    times: u32,
+}
+
+// each line of the plan is read two ways - part 1 follows the direction and
+// distance, part 2 the ones hidden in the color
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Step {
+    plan: Instruction,
+    color: Instruction,
+}
+
+fn parse_step(input: &str, line: &str) -> Result<Step, ParseError> {
+    let (direction, rest) = aoc_parse::split_once(input, line, " ")?;
+    let direction = match direction {
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        other => {
+            let message = format!("expected U, D, L or R, found '{other}'");
+            return Err(ParseError::at(input, other, message));
+        }
+    };
+    let (times, color) = aoc_parse::split_once(input, rest, " ")?;
+    let times = aoc_parse::parse(input, times, "a distance")?;
+    let color = parse_color(input, color)?;
+    Ok(Step {
+        plan: Instruction { direction, times },
+        color: decode_color(input, color)?,
+    })
+}
+
+// the hex digits in (#70c710)
+fn parse_color<'a>(input: &str, color: &'a str) -> Result<&'a str, ParseError> {
+    let hex = aoc_parse::strip_prefix(input, color, "(#")?;
+    let hex = hex
+        .strip_suffix(')')
+        .ok_or_else(|| ParseError::at(input, aoc_parse::end(hex), "expected ')'"))?;
+    if hex.len() != 6 || !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        let message = format!("expected 6 hex digits, found '{hex}'");
+        return Err(ParseError::at(input, hex, message));
+    }
+    Ok(hex)
+}
+
+// the first five hex digits are the distance, the last one the direction
+fn decode_color(input: &str, hex: &str) -> Result<Instruction, ParseError> {
+    let times = u32::from_str_radix(&hex[0..5], 16).expect("valid hex number");
+    let direction = match &hex[5..6] {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        other => {
+            let message = format!("expected a direction from 0 to 3, found '{other}'");
+            return Err(ParseError::at(input, &hex[5..6], message));
+        }
+    };
+    Ok(Instruction { direction, times })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Step>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| parse_step(input, line))
+        .collect()
+}
+
+// the corners of the trench, back to where it started
+fn dig_border<'a>(instructions: impl Iterator<Item = &'a Instruction>) -> Vec<(i64, i64)> {
+    let mut point = (0, 0);
+    let mut border = vec![point];
+
+    for instruction in instructions {
+        let (x_inc, y_inc) = match instruction.direction {
+            Direction::Up => (0, 1),
+            Direction::Down => (0, -1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        };
+        let times = instruction.times as i64;
+        point = (point.0 + x_inc * times, point.1 + y_inc * times);
+        border.push(point);
+    }
+
+    border
+}
+
+fn get_area<'a>(instructions: impl Iterator<Item = &'a Instruction> + Clone) -> i64 {
+    let border = dig_border(instructions.clone());
+
+    // shoelace formula
+    let area = border
+        .windows(2)
+        .map(|pair| pair[0].0 * pair[1].1 - pair[0].1 * pair[1].0)
+        .sum::<i64>()
+        .abs()
+        / 2;
+
+    let border: i64 = instructions
+        .map(|instruction| instruction.times as i64)
+        .sum();
+
+    // pick's theorem
+    // A = area
+    // b = boundary
+    // i = interior
+    //
+    // A = i + b/2 - 1
+    // i = A - b/2 + 1
+    let interior = area - border / 2 + 1;
+    interior + border
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(steps: &Self::Parsed<'_>) -> impl Display {
+        get_area(steps.iter().map(|step| &step.plan))
+    }
+
+    fn part2(steps: &Self::Parsed<'_>) -> impl Display {
+        get_area(steps.iter().map(|step| &step.color))
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,10 @@
+use aoc_workflows::parse_input;
+
+// the route each part takes through the workflows
+fn main() {
+    let input = &aoc_input::load_or_exit(2023, 19);
+    let (workflows, parts) = aoc_parse::or_exit(parse_input(input), input);
+    for part in parts {
+        println!("{part}: {}", workflows.explain(&part));
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use aoc_workflows::{parse_input, Part, Parts, Workflows};
+use std::fmt::Display;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = (Workflows<'a>, Vec<Part>);
+
+    fn parse(input: &str) -> Result<(Workflows<'_>, Vec<Part>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((workflows, parts): &Self::Parsed<'_>) -> impl Display {
+        parts
+            .iter()
+            .filter(|part| workflows.accepts(part))
+            .map(|part| part.total())
+            .sum::<u32>()
+    }
+
+    fn part2((workflows, _): &Self::Parsed<'_>) -> impl Display {
+        // each rating can be anything from 1 to 4000
+        workflows.count_accepted(Parts::new([1..4001, 1..4001, 1..4001, 1..4001]))
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
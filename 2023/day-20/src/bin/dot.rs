@@ -0,0 +1,10 @@
+use y2023_day_20::{cycle_counters, machine::Machine};
+
+// the module wiring as Graphviz, with the counters part 2 relies on highlighted
+fn main() {
+    let input = &aoc_input::load_or_exit(2023, 20);
+    let machine = aoc_parse::or_exit(Machine::parse(input), input);
+    // without a single rx source there are no counters to highlight
+    let counters = cycle_counters(&machine).unwrap_or_default();
+    println!("{}", machine.to_dot(&counters));
+}
@@ -0,0 +1,118 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+use crate::machine::{parse_definitions, Definition, HighPulses, Machine, PulseCounter};
+
+pub mod machine;
+
+// plenty for the real input, where each cycle is a few thousand presses
+const MAX_PRESSES: u64 = 100_000;
+
+fn euclidean_gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        let min = a.min(b);
+        let max = a.max(b);
+        euclidean_gcd(min, max % min)
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    (a * b) / euclidean_gcd(a, b)
+}
+
+// need low pulse to rx...
+// there is one conjunction module that sends signal to rx, so that module would need a high pulse.
+// rx's source has another four conjunction sources, so each of these
+// needs to send a high on the same press - they each do so on a cycle
+pub fn cycle_counters<'a>(machine: &Machine<'a>) -> Option<Vec<&'a str>> {
+    let [rx_source] = machine.sources("rx")[..] else {
+        return None;
+    };
+    Some(machine.sources(rx_source))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Definition<'a>>;
+
+    fn parse(input: &str) -> Result<Vec<Definition<'_>>, ParseError> {
+        parse_definitions(input)
+    }
+
+    fn part1(definitions: &Self::Parsed<'_>) -> impl Display {
+        let mut machine = Machine::new(definitions);
+        let mut counter = PulseCounter::default();
+        for _ in 0..1000 {
+            machine.press(&mut counter);
+        }
+        counter.high * counter.low
+    }
+
+    fn part2(definitions: &Self::Parsed<'_>) -> impl Display {
+        let mut machine = Machine::new(definitions);
+
+        // watch for the first high from each counter
+        let counters = cycle_counters(&machine).expect("checked counters");
+        let mut probe = HighPulses::new(counters);
+        while !probe.seen_all() {
+            assert!(
+                machine.presses() < MAX_PRESSES,
+                "no cycle found in {MAX_PRESSES} presses"
+            );
+            machine.press(&mut probe);
+        }
+
+        probe.first.values().copied().fold(1, lcm)
+    }
+
+    // part 2 needs a single module sending to rx
+    fn check(input: &str, definitions: &Self::Parsed<'_>, part: u8) -> Result<(), ParseError> {
+        if part != 2 {
+            return Ok(());
+        }
+        match Machine::new(definitions).sources("rx")[..] {
+            [_] => Ok(()),
+            [] => Err(ParseError::at(
+                input,
+                aoc_parse::end(input.trim()),
+                "expected a module sending to rx",
+            )),
+            [_, second, ..] => Err(ParseError::at(
+                input,
+                second,
+                "expected only one module sending to rx",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_solution::part2;
+
+    #[test]
+    fn rx_sources() {
+        assert_eq!(
+            part2::<Day>("broadcaster -> a\n%a -> b\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 8: expected a module sending to rx"
+        );
+        assert_eq!(
+            part2::<Day>("broadcaster -> a, b\n%a -> rx\n%b -> rx\n")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 2: expected only one module sending to rx"
+        );
+    }
+}
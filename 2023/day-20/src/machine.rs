@@ -0,0 +1,418 @@
+use aoc_dot::Dot;
+use aoc_parse::ParseError;
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+pub trait Module<'a> {
+    fn kind(&self) -> Kind;
+
+    fn destinations(&self) -> &[&'a str];
+
+    // called for each module wired into this one, before any pulses are sent
+    fn connect(&mut self, _source: &'a str) {}
+
+    // the pulse to send on to every destination, if any
+    fn receive(&mut self, pulse: Pulse, source: &'a str) -> Option<Pulse>;
+}
+
+pub struct Broadcaster<'a> {
+    destinations: Vec<&'a str>,
+}
+
+impl<'a> Module<'a> for Broadcaster<'a> {
+    fn kind(&self) -> Kind {
+        Kind::Broadcaster
+    }
+
+    fn destinations(&self) -> &[&'a str] {
+        &self.destinations
+    }
+
+    fn receive(&mut self, pulse: Pulse, _source: &'a str) -> Option<Pulse> {
+        Some(pulse)
+    }
+}
+
+pub struct FlipFlop<'a> {
+    on: bool,
+    destinations: Vec<&'a str>,
+}
+
+impl<'a> Module<'a> for FlipFlop<'a> {
+    fn kind(&self) -> Kind {
+        Kind::FlipFlop
+    }
+
+    fn destinations(&self) -> &[&'a str] {
+        &self.destinations
+    }
+
+    fn receive(&mut self, pulse: Pulse, _source: &'a str) -> Option<Pulse> {
+        if pulse == Pulse::High {
+            return None;
+        }
+        self.on = !self.on;
+        Some(if self.on { Pulse::High } else { Pulse::Low })
+    }
+}
+
+pub struct Conjunction<'a> {
+    memory: HashMap<&'a str, Pulse>, // memory slots for each source
+    destinations: Vec<&'a str>,
+}
+
+impl<'a> Module<'a> for Conjunction<'a> {
+    fn kind(&self) -> Kind {
+        Kind::Conjunction
+    }
+
+    fn destinations(&self) -> &[&'a str] {
+        &self.destinations
+    }
+
+    fn connect(&mut self, source: &'a str) {
+        self.memory.insert(source, Pulse::Low);
+    }
+
+    fn receive(&mut self, pulse: Pulse, source: &'a str) -> Option<Pulse> {
+        self.memory.insert(source, pulse);
+        if self.memory.values().all(|p| p == &Pulse::High) {
+            Some(Pulse::Low)
+        } else {
+            Some(Pulse::High)
+        }
+    }
+}
+
+// anything only ever sent to, like output or rx - it just counts what arrives
+#[derive(Default)]
+pub struct Output {
+    pub low: u64,
+    pub high: u64,
+}
+
+impl<'a> Module<'a> for Output {
+    fn kind(&self) -> Kind {
+        Kind::Output
+    }
+
+    fn destinations(&self) -> &[&'a str] {
+        &[]
+    }
+
+    fn receive(&mut self, pulse: Pulse, _source: &'a str) -> Option<Pulse> {
+        match pulse {
+            Pulse::High => self.high += 1,
+            Pulse::Low => self.low += 1,
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Message<'a> {
+    pub pulse: Pulse,
+    pub source: &'a str,
+    pub destination: &'a str,
+}
+
+// sees every message as it's delivered, along with which press it came from
+pub trait Probe<'a> {
+    fn observe(&mut self, presses: u64, message: &Message<'a>);
+}
+
+#[derive(Default)]
+pub struct PulseCounter {
+    pub low: u64,
+    pub high: u64,
+}
+
+impl<'a> Probe<'a> for PulseCounter {
+    fn observe(&mut self, _presses: u64, message: &Message<'a>) {
+        match message.pulse {
+            Pulse::High => self.high += 1,
+            Pulse::Low => self.low += 1,
+        }
+    }
+}
+
+// the first press on which each of the named modules sent a high pulse
+pub struct HighPulses<'a> {
+    names: Vec<&'a str>,
+    pub first: HashMap<&'a str, u64>,
+}
+
+impl<'a> HighPulses<'a> {
+    pub fn new(names: Vec<&'a str>) -> Self {
+        HighPulses {
+            names,
+            first: HashMap::new(),
+        }
+    }
+
+    pub fn seen_all(&self) -> bool {
+        self.names.iter().all(|name| self.first.contains_key(name))
+    }
+}
+
+impl<'a> Probe<'a> for HighPulses<'a> {
+    fn observe(&mut self, presses: u64, message: &Message<'a>) {
+        if message.pulse == Pulse::High && self.names.contains(&message.source) {
+            self.first.entry(message.source).or_insert(presses);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    // only ever sent to, so never in the input
+    Output,
+}
+
+// a line of the input, before any modules are built from it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Definition<'a> {
+    kind: Kind,
+    name: &'a str,
+    destinations: Vec<&'a str>,
+}
+
+// %a -> inv, con
+pub fn parse_definitions(input: &str) -> Result<Vec<Definition<'_>>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (name, destinations) = aoc_parse::split_once(input, line, " -> ")?;
+            let (kind, name) = if name == "broadcaster" {
+                (Kind::Broadcaster, name)
+            } else if let Some(name) = name.strip_prefix('&') {
+                (Kind::Conjunction, name)
+            } else if let Some(name) = name.strip_prefix('%') {
+                (Kind::FlipFlop, name)
+            } else {
+                let message = format!("expected broadcaster, %name or &name, found '{name}'");
+                return Err(ParseError::at(input, name, message));
+            };
+            let destinations = destinations.split(',').map(|s| s.trim()).collect();
+            Ok(Definition {
+                kind,
+                name,
+                destinations,
+            })
+        })
+        .collect()
+}
+
+pub struct Machine<'a> {
+    modules: HashMap<&'a str, Box<dyn Module<'a> + 'a>>,
+    queue: VecDeque<Message<'a>>,
+    presses: u64,
+}
+
+impl<'a> Machine<'a> {
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Machine::new(&parse_definitions(input)?))
+    }
+
+    // a fresh machine, with every module in its starting state
+    pub fn new(definitions: &[Definition<'a>]) -> Self {
+        let mut modules: HashMap<&'a str, Box<dyn Module<'a> + 'a>> = HashMap::new();
+
+        for Definition {
+            kind,
+            name,
+            destinations,
+        } in definitions
+        {
+            let destinations = destinations.clone();
+            let module: Box<dyn Module<'a> + 'a> = match kind {
+                Kind::Broadcaster => Box::new(Broadcaster { destinations }),
+                Kind::Conjunction => Box::new(Conjunction {
+                    memory: HashMap::new(),
+                    destinations,
+                }),
+                Kind::FlipFlop => Box::new(FlipFlop {
+                    on: false,
+                    destinations,
+                }),
+                Kind::Output => Box::new(Output::default()),
+            };
+            modules.insert(name, module);
+        }
+
+        // wire up the sources, adding an output for any unknown destinations
+        let wires = modules
+            .iter()
+            .flat_map(|(source, module)| {
+                module
+                    .destinations()
+                    .iter()
+                    .map(|destination| (*source, *destination))
+            })
+            .collect::<Vec<_>>();
+        for (source, destination) in wires {
+            modules
+                .entry(destination)
+                .or_insert_with(|| Box::new(Output::default()))
+                .connect(source);
+        }
+
+        Machine {
+            modules,
+            queue: VecDeque::new(),
+            presses: 0,
+        }
+    }
+
+    // the modules that send to name
+    pub fn sources(&self, name: &str) -> Vec<&'a str> {
+        let mut sources = self
+            .modules
+            .iter()
+            .filter(|(_, module)| module.destinations().contains(&name))
+            .map(|(source, _)| *source)
+            .collect::<Vec<_>>();
+        sources.sort();
+        sources
+    }
+
+    // the wiring, with each kind of module drawn differently and the named
+    // modules highlighted
+    pub fn to_dot(&self, highlight: &[&str]) -> Dot {
+        let mut dot = Dot::digraph("modules");
+        let mut names = self.modules.keys().copied().collect::<Vec<_>>();
+        names.sort();
+        for name in &names {
+            let module = &self.modules[name];
+            let (shape, label) = match module.kind() {
+                Kind::FlipFlop => ("box", format!("%{name}")),
+                Kind::Conjunction => ("invtrapezium", format!("&{name}")),
+                Kind::Output => ("doublecircle", name.to_string()),
+                Kind::Broadcaster => ("house", name.to_string()),
+            };
+            let mut attrs = vec![("shape", shape), ("label", &label)];
+            if highlight.contains(name) {
+                attrs.extend([("style", "filled"), ("fillcolor", "gold")]);
+            }
+            dot.node(name, &attrs);
+        }
+        for name in &names {
+            for destination in self.modules[name].destinations() {
+                dot.edge(name, destination, &[]);
+            }
+        }
+        dot
+    }
+
+    pub fn presses(&self) -> u64 {
+        self.presses
+    }
+
+    // pushes the button, delivering messages until everything settles
+    pub fn press(&mut self, probe: &mut impl Probe<'a>) {
+        self.presses += 1;
+        self.queue.push_back(Message {
+            pulse: Pulse::Low,
+            source: "button",
+            destination: "broadcaster",
+        });
+        while let Some(message) = self.queue.pop_front() {
+            probe.observe(self.presses, &message);
+            let Some(module) = self.modules.get_mut(message.destination) else {
+                continue;
+            };
+            if let Some(pulse) = module.receive(message.pulse, message.source) {
+                for destination in module.destinations() {
+                    self.queue.push_back(Message {
+                        pulse,
+                        source: message.destination,
+                        destination,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outputs_are_added() {
+        let machine = Machine::parse(
+            "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output",
+        )
+        .unwrap();
+        assert_eq!(machine.sources("output"), vec!["con"]);
+        assert_eq!(machine.sources("con"), vec!["a", "b"]);
+        assert!(machine.modules.contains_key("output"));
+    }
+
+    #[test]
+    fn dot() {
+        let machine = Machine::parse(
+            "broadcaster -> a
+%a -> inv
+&inv -> rx",
+        )
+        .unwrap();
+        assert_eq!(
+            machine.to_dot(&["inv"]).to_string(),
+            r#"digraph "modules" {
+    "a" [shape="box", label="%a"];
+    "broadcaster" [shape="house", label="broadcaster"];
+    "inv" [shape="invtrapezium", label="&inv", style="filled", fillcolor="gold"];
+    "rx" [shape="doublecircle", label="rx"];
+    "a" -> "inv";
+    "broadcaster" -> "a";
+    "inv" -> "rx";
+}"#
+        );
+    }
+
+    #[test]
+    fn probes() {
+        let mut machine = Machine::parse(
+            "broadcaster -> a
+%a -> inv
+&inv -> output",
+        )
+        .unwrap();
+        let mut counter = PulseCounter::default();
+        let mut high = HighPulses::new(vec!["a", "inv"]);
+        machine.press(&mut counter);
+        // button -> broadcaster -> a, then a goes high so inv sends low
+        assert_eq!((counter.low, counter.high), (3, 1));
+        machine.press(&mut high);
+        assert!(!high.seen_all());
+        // a goes low, so inv sends high
+        machine.press(&mut high);
+        machine.press(&mut high);
+        assert_eq!(high.first, HashMap::from([("inv", 2), ("a", 3)]));
+        assert!(high.seen_all());
+        assert_eq!(machine.presses(), 4);
+    }
+
+    #[test]
+    fn invalid() {
+        let error = parse_definitions("broadcaster -> a\na -> b").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected broadcaster, %name or &name, found 'a'"
+        );
+    }
+}
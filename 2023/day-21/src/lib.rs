@@ -0,0 +1,215 @@
+use aoc_grid::{Grid, Point};
+use aoc_parse::ParseError;
+use aoc_search::distances;
+use aoc_solution::Solution;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+
+const STEPS: u32 = 64;
+
+const INFINITE_STEPS: u32 = 26501365;
+
+fn is_walkable(ch: char) -> bool {
+    ch == '.' || ch == 'S'
+}
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    let grid = Grid::try_parse(input, |ch| matches!(ch, '.' | '#' | 'S').then_some(ch))?;
+    if grid.position(|ch| *ch == 'S').is_none() {
+        return Err(ParseError::at(
+            input,
+            aoc_parse::end(input),
+            "expected a start S",
+        ));
+    }
+    Ok(grid)
+}
+
+fn shortest_paths(grid: &Grid<char>) -> HashMap<Point, u32> {
+    let start = grid
+        .position(|ch| *ch == 'S')
+        .expect("a start, checked when parsing");
+    distances(start, |&point| {
+        grid.neighbours(point)
+            .filter(|neighbour| is_walkable(grid[*neighbour]))
+            .map(|neighbour| (neighbour, 1))
+            .collect::<Vec<_>>()
+    })
+}
+
+pub fn plots_reached(grid: &Grid<char>, steps: u32) -> usize {
+    let shortest_paths = shortest_paths(grid);
+
+    // assuming a linear path like this: A -> B -> C -> D -> E -> F
+    // the elf can go from start to finish: A -> B -> C -> D -> E -> F
+    // or he can repeat 2 steps and go: A -> B -> C -> D -> C -> D
+    // or he can repeat 2 steps earlier and go: A -> B -> A -> B -> C -> D
+    // or he can repeat 4 steps go: A -> B -> A -> B -> A -> B
+    // and we are always dealing with an even number of steps
+    // so basically it seems like he can either go all the way to a N distance point
+    // or he can end on any point between that has an even number of steps
+
+    shortest_paths
+        .values()
+        .filter(|&&distance| distance <= steps && distance % 2 == 0)
+        .count()
+}
+
+// the grid repeats infinitely, so wrap the point back onto the original
+fn get(grid: &Grid<char>, point: &(isize, isize)) -> char {
+    let x = point.0.rem_euclid(grid.width() as isize) as usize;
+    let y = point.1.rem_euclid(grid.height() as isize) as usize;
+    grid[Point::new(x, y)]
+}
+
+// shortest distances from the start, on the infinitely repeating grid, up to the given max
+fn infinite_shortest_paths(grid: &Grid<char>, max: u32) -> HashMap<(isize, isize), u32> {
+    let start = grid
+        .position(|ch| *ch == 'S')
+        .expect("a start, checked when parsing");
+    let start = (start.x as isize, start.y as isize);
+    let mut paths = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(point) = queue.pop_front() {
+        let distance = paths[&point];
+        if distance == max {
+            continue;
+        }
+        for neighbour in [
+            (point.0, point.1 - 1),
+            (point.0 + 1, point.1),
+            (point.0, point.1 + 1),
+            (point.0 - 1, point.1),
+        ] {
+            if get(grid, &neighbour) != '#' && !paths.contains_key(&neighbour) {
+                paths.insert(neighbour, distance + 1);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    paths
+}
+
+fn count_reachable(paths: &HashMap<(isize, isize), u32>, steps: u32) -> u64 {
+    // the elf can step back and forth, so any plot reached with the same parity is also reachable
+    paths
+        .values()
+        .filter(|&&distance| distance <= steps && distance % 2 == steps % 2)
+        .count() as u64
+}
+
+pub fn infinite_plots_reached(grid: &Grid<char>, steps: u32) -> u64 {
+    /*
+    for the part2 puzzle, the grid is an infinite repeating canvas in all directions
+    let's explore this simplified example of a 5 x 5 grid with distances from the center S tile at (2,2)
+
+    43234
+    32123
+    21S12
+    32123
+    43234
+
+    if the elf has 4 steps he can already enter another grid in all directions
+    if he simply goes right for 4 steps he will be at (6,2) which is effectively the same as (1,2) (ie, (6 % col_len,2) -> (6 % 5,2))
+
+    43234
+    32123
+    21S1221 <--
+    32123
+    43234
+
+    the distance at the edge of the grid he left was 2 and he's ended up at a tile with distance 1 (from the grid center)
+    so I don't think the number of steps is super important yet but the fact that it is odd is, because originally the elf
+    started at S and that tile had no distance, but now we moving in an opposite direction, so when considering other
+    grids beyond the first, we only consider odd distances
+
+    but if he continues for another 4 steps (8 in total now) he again arrives at a tile with even distance
+
+    43234
+    32123
+    21S1221S122 <--
+    32123
+    43234
+
+    so for the initial grid we only consider even distances, then the next grid only odd,
+    then the next only even, then the next only odd, and so on
+
+    ... some time later ...
+
+    ok I need to change my approach - I've looked at other people's solutions and it's more work than I have time for right now
+
+    ... even later ...
+
+    the number of plots reached after remainder + n * width steps grows quadratically in n once
+    the diamond has spread across a few grids, so walk the first few grids properly and then
+    extrapolate from the last three samples
+     */
+    let width = grid.height() as u32;
+    let remainder = steps % width;
+    let samples = 6;
+    let paths = infinite_shortest_paths(grid, steps.min(remainder + samples * width));
+    if steps <= remainder + samples * width {
+        return count_reachable(&paths, steps);
+    }
+
+    let a = count_reachable(&paths, remainder + (samples - 2) * width) as i64;
+    let b = count_reachable(&paths, remainder + (samples - 1) * width) as i64;
+    let c = count_reachable(&paths, remainder + samples * width) as i64;
+    let first_diff = b - a;
+    let second_diff = c - 2 * b + a;
+    let n = ((steps - remainder) / width - (samples - 2)) as i64;
+
+    (a + n * first_diff + n * (n - 1) / 2 * second_diff) as u64
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    // the garden and how many steps the elf takes in each part
+    type Parsed<'a> = (Grid<char>, u32, u32);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Ok((parse_input(input)?, STEPS, INFINITE_STEPS))
+    }
+
+    fn part1((grid, steps, _): &Self::Parsed<'_>) -> impl Display {
+        plots_reached(grid, *steps)
+    }
+
+    // the garden repeats forever in every direction
+    fn part2((grid, _, steps): &Self::Parsed<'_>) -> impl Display {
+        infinite_plots_reached(grid, *steps)
+    }
+
+    fn set((_, steps, infinite_steps): &mut Self::Parsed<'_>, name: &str, value: u64) -> bool {
+        match (name, u32::try_from(value)) {
+            ("steps", Ok(value)) => {
+                *steps = value;
+                true
+            }
+            ("infinite_steps", Ok(value)) => {
+                *infinite_steps = value;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_start() {
+        let error = parse_input("..\n.#").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: expected a start S");
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+use crate::tower::Tower;
+
+mod tower;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Tower;
+
+    fn parse(input: &str) -> Result<Tower, ParseError> {
+        Tower::parse(input)
+    }
+
+    fn part1(tower: &Self::Parsed<'_>) -> impl Display {
+        (0..tower.len())
+            .filter(|&brick| tower.is_safe(brick))
+            .count()
+    }
+
+    fn part2(tower: &Self::Parsed<'_>) -> impl Display {
+        tower.would_fall().iter().sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
@@ -0,0 +1,249 @@
+use aoc_parse::ParseError;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Point3d {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+impl Point3d {
+    // 1,0,1
+    fn parse(input: &str, point: &str) -> Result<Self, ParseError> {
+        let (x, rest) = aoc_parse::split_once(input, point, ",")?;
+        let (y, z) = aoc_parse::split_once(input, rest, ",")?;
+        Ok(Self {
+            x: aoc_parse::parse(input, x, "a coordinate")?,
+            y: aoc_parse::parse(input, y, "a coordinate")?,
+            z: aoc_parse::parse(input, z, "a coordinate")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Brick {
+    start: Point3d,
+    end: Point3d,
+}
+
+impl Brick {
+    fn new(start: Point3d, end: Point3d) -> Self {
+        // assumption: end is always larger than start
+        assert!(start.x <= end.x);
+        assert!(start.y <= end.y);
+        assert!(start.z <= end.z);
+        Brick { start, end }
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.start.x..=self.end.x).flat_map(|x| (self.start.y..=self.end.y).map(move |y| (x, y)))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Brick>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (start, end) = aoc_parse::split_once(input, line, "~")?;
+            let (start, end) = (Point3d::parse(input, start)?, Point3d::parse(input, end)?);
+            if start.x > end.x || start.y > end.y || start.z > end.z {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "expected the end of the brick to be past the start",
+                ));
+            }
+            Ok(Brick::new(start, end))
+        })
+        .collect()
+}
+
+// the bricks once they've all fallen, as which rest on which - bricks are
+// numbered in the order they're given
+#[derive(Debug)]
+pub struct Tower {
+    // the settled bottom and top of each brick
+    levels: Vec<(usize, usize)>,
+    // the bricks resting directly on each brick
+    supports: Vec<Vec<usize>>,
+    // the bricks each brick rests directly on, none for the ground
+    supported_by: Vec<Vec<usize>>,
+    // lowest first, so every brick comes after everything holding it up
+    order: Vec<usize>,
+}
+
+impl Tower {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let bricks = parse_input(input)?;
+        let mut order = (0..bricks.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| bricks[i].start.z);
+
+        // dropping the lowest first, the height map has the top of the
+        // highest brick so far over each (x, y) along with which brick it is
+        let width = bricks.iter().map(|b| b.end.x + 1).max().unwrap_or(0);
+        let depth = bricks.iter().map(|b| b.end.y + 1).max().unwrap_or(0);
+        let mut height_map: Vec<Vec<Option<(usize, usize)>>> = vec![vec![None; depth]; width];
+        let mut levels = vec![(0, 0); bricks.len()];
+        let mut supports = vec![vec![]; bricks.len()];
+        let mut supported_by: Vec<Vec<usize>> = vec![vec![]; bricks.len()];
+        for &i in &order {
+            let brick = &bricks[i];
+            let below = brick
+                .cells()
+                .filter_map(|(x, y)| height_map[x][y])
+                .collect::<Vec<_>>();
+            let top = below.iter().map(|(top, _)| *top).max().unwrap_or(0);
+            for &(_, j) in below.iter().filter(|(t, _)| *t == top) {
+                if !supported_by[i].contains(&j) {
+                    supported_by[i].push(j);
+                    supports[j].push(i);
+                }
+            }
+            let bottom = top + 1;
+            let level = (bottom, bottom + brick.end.z - brick.start.z);
+            for (x, y) in brick.cells() {
+                height_map[x][y] = Some((level.1, i));
+            }
+            levels[i] = level;
+        }
+
+        Ok(Tower {
+            levels,
+            supports,
+            supported_by,
+            order,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    // nothing falls without it, as everything on it rests on something else too
+    pub fn is_safe(&self, brick: usize) -> bool {
+        self.supports[brick]
+            .iter()
+            .all(|&above| self.supported_by[above].len() > 1)
+    }
+
+    // how many other bricks fall if each brick is taken away
+    //
+    // A brick falls exactly when every path down to the ground goes through
+    // the missing one, i.e. when it's dominated by it in the graph of what
+    // rests on what, with the ground as the root. The immediate dominator of
+    // a brick is the deepest common dominator of everything under it, and
+    // everything under it is settled first, so one pass up the tower builds
+    // the whole dominator tree - then each count is the size of a subtree.
+    pub fn would_fall(&self) -> Vec<usize> {
+        // None being the ground
+        let mut dominator: Vec<Option<usize>> = vec![None; self.len()];
+        let mut depth = vec![0; self.len()];
+        let depth_of = |depth: &[usize], brick: Option<usize>| brick.map_or(0, |b| depth[b]);
+        for &brick in &self.order {
+            let mut below = self.supported_by[brick].iter().map(|&b| Some(b));
+            let first = below.next().unwrap_or(None);
+            let common = below.fold(first, |mut a, mut b| {
+                while a != b {
+                    if depth_of(&depth, a) >= depth_of(&depth, b) {
+                        a = a.and_then(|a| dominator[a]);
+                    } else {
+                        b = b.and_then(|b| dominator[b]);
+                    }
+                }
+                a
+            });
+            dominator[brick] = common;
+            depth[brick] = depth_of(&depth, common) + 1;
+        }
+
+        // from the top down, so each subtree is finished before it's added on
+        let mut fall = vec![0; self.len()];
+        for &brick in self.order.iter().rev() {
+            if let Some(parent) = dominator[brick] {
+                fall[parent] += fall[brick] + 1;
+            }
+        }
+        fall
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the bricks are A to G in the puzzle
+    const A: usize = 0;
+    const B: usize = 1;
+    const C: usize = 2;
+    const D: usize = 3;
+    const E: usize = 4;
+    const F: usize = 5;
+    const G: usize = 6;
+
+    #[test]
+    fn settles() {
+        let tower = Tower::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            tower.levels,
+            [(1, 1), (2, 2), (2, 2), (3, 3), (3, 3), (4, 4), (5, 6)]
+        );
+        assert_eq!(tower.supports[A], [B, C]);
+        assert_eq!(tower.supported_by[D], [B, C]);
+        assert_eq!(tower.supported_by[F], [D, E]);
+        assert_eq!(tower.supports[F], [G]);
+        assert!(tower.supports[G].is_empty());
+    }
+
+    #[test]
+    fn safe() {
+        let tower = Tower::parse(EXAMPLE).unwrap();
+        let safe = (0..tower.len())
+            .filter(|&brick| tower.is_safe(brick))
+            .collect::<Vec<_>>();
+        assert_eq!(safe, [B, C, D, E, G]);
+    }
+
+    #[test]
+    fn chain_reactions() {
+        let tower = Tower::parse(EXAMPLE).unwrap();
+        // everything else rests on A in the end, and G on F
+        assert_eq!(tower.would_fall(), [6, 0, 0, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn invalid() {
+        let error = Tower::parse("1,0,1~1,2,1\n0,0,2~0,2").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 9: expected ','");
+        let error = Tower::parse("1,0,3~1,2,1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected the end of the brick to be past the start"
+        );
+    }
+
+    #[test]
+    fn side_by_side() {
+        // two towers, one on the ground and one on a wide base
+        let tower = Tower::parse(
+            "0,0,1~0,0,1
+0,0,2~0,0,3
+2,0,1~3,0,1
+2,0,5~2,0,5
+3,0,5~3,0,5
+2,0,7~3,0,7",
+        )
+        .unwrap();
+        assert_eq!(tower.would_fall(), [1, 0, 3, 0, 0, 0]);
+        assert_eq!(tower.levels[5], (3, 3));
+    }
+}
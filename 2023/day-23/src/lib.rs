@@ -0,0 +1,56 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+use crate::trails::{parse_input, Graph};
+
+mod trails;
+
+pub struct Day;
+
+impl Solution for Day {
+    // the trails, once with slippy slopes and once without
+    type Parsed<'a> = (Graph, Graph);
+
+    fn parse(input: &str) -> Result<(Graph, Graph), ParseError> {
+        let grid = parse_input(input)?;
+        Ok((Graph::new(&grid, true), Graph::new(&grid, false)))
+    }
+
+    fn part1((slippy, _): &Self::Parsed<'_>) -> impl Display {
+        slippy.longest_path().expect("a checked path to the end")
+    }
+
+    // the slopes are dry now, so any corridor can be walked either way
+    fn part2((_, dry): &Self::Parsed<'_>) -> impl Display {
+        dry.longest_path().expect("a checked path to the end")
+    }
+
+    fn check(input: &str, (slippy, dry): &Self::Parsed<'_>, part: u8) -> Result<(), ParseError> {
+        let graph = if part == 1 { slippy } else { dry };
+        if !graph.reaches_end() {
+            let message = "expected a way from the start to the end";
+            return Err(ParseError::at(input, aoc_parse::end(input.trim()), message));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_solution::part1;
+
+    #[test]
+    fn walled_off() {
+        assert_eq!(
+            part1::<Day>("#.#\n###\n#.#").unwrap_err().to_string(),
+            "line 3, column 4: expected a way from the start to the end"
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use aoc_grid::{Dir, Grid, Point};
+use aoc_parse::ParseError;
+use std::collections::HashMap;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Tile {
+    Start,
+    End,
+    Path,
+    Forest,
+    // can only be stepped onto going downhill, in this direction
+    Slope(Dir),
+}
+
+pub fn parse_input(input: &str) -> Result<Grid<Tile>, ParseError> {
+    let mut grid = Grid::try_parse(input, |c| match c {
+        '#' => Some(Tile::Forest),
+        'v' => Some(Tile::Slope(Dir::South)),
+        '^' => Some(Tile::Slope(Dir::North)),
+        '>' => Some(Tile::Slope(Dir::East)),
+        '<' => Some(Tile::Slope(Dir::West)),
+        '.' => Some(Tile::Path),
+        _ => None,
+    })?;
+    // the only paths on the top and bottom rows are the start and end
+    let last_y = grid.height().saturating_sub(1);
+    for x in 0..grid.width() {
+        for (y, tile) in [(0, Tile::Start), (last_y, Tile::End)] {
+            let point = Point::new(x, y);
+            if grid[point] == Tile::Path {
+                grid[point] = tile;
+            }
+        }
+    }
+
+    let lines = input.trim().lines().collect::<Vec<_>>();
+    let first = lines.first().copied().unwrap_or(aoc_parse::end(input));
+    let last = lines.last().copied().unwrap_or(aoc_parse::end(input));
+    for (tile, line, row) in [(Tile::Start, first, "top"), (Tile::End, last, "bottom")] {
+        if grid.position(|found| *found == tile).is_none() {
+            let message = format!("expected a path in the {row} row");
+            return Err(ParseError::at(input, line, message));
+        }
+    }
+    let junctions = junctions(&grid).len();
+    if junctions > 64 {
+        let message = format!("expected at most 64 junctions, found {junctions}");
+        return Err(ParseError::at(input, aoc_parse::end(input.trim()), message));
+    }
+    Ok(grid)
+}
+
+// the first start and end, which parse_input made sure are there
+pub fn start_point(grid: &Grid<Tile>) -> Point {
+    grid.position(|tile| tile == &Tile::Start)
+        .expect("a checked start")
+}
+
+pub fn end_point(grid: &Grid<Tile>) -> Point {
+    grid.position(|tile| tile == &Tile::End)
+        .expect("a checked end")
+}
+
+// the start, the end and any tile with more than two ways out
+fn junctions(grid: &Grid<Tile>) -> Vec<Point> {
+    grid.iter()
+        .filter(|(point, tile)| match tile {
+            Tile::Forest => false,
+            Tile::Start | Tile::End => true,
+            _ => open_dirs(grid, *point).count() > 2,
+        })
+        .map(|(point, _)| point)
+        .collect()
+}
+
+// the trails reduced to the junctions between them: nodes are the start, the
+// end and any tile with more than two ways out, and each edge is a single
+// track corridor weighted by its length
+#[derive(Debug)]
+pub struct Graph {
+    // (node, length) for each node's outgoing corridors
+    pub edges: Vec<Vec<(usize, usize)>>,
+    pub start: usize,
+    pub end: usize,
+}
+
+fn open_dirs(grid: &Grid<Tile>, point: Point) -> impl Iterator<Item = (Dir, Point)> + '_ {
+    Dir::ALL.into_iter().filter_map(move |dir| {
+        grid.step(point, dir)
+            .filter(|next| grid[*next] != Tile::Forest)
+            .map(|next| (dir, next))
+    })
+}
+
+impl Graph {
+    // with slippy slopes a corridor can only be walked downhill, without them
+    // every corridor goes both ways
+    pub fn new(grid: &Grid<Tile>, slippy: bool) -> Self {
+        // no more than 64, as parse_input checked
+        let junctions = junctions(grid);
+        let index = junctions
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (*point, i))
+            .collect::<HashMap<_, _>>();
+
+        let mut edges = vec![vec![]; junctions.len()];
+        for (from, junction) in junctions.iter().enumerate() {
+            for (first_dir, first) in open_dirs(grid, *junction) {
+                // follow the corridor until the next junction
+                let (mut previous, mut current, mut dir) = (*junction, first, first_dir);
+                let mut length = 1;
+                let mut downhill = true;
+                loop {
+                    if let Tile::Slope(slope) = grid[current] {
+                        downhill &= slope == dir;
+                    }
+                    if let Some(&to) = index.get(&current) {
+                        if downhill || !slippy {
+                            edges[from].push((to, length));
+                        }
+                        break;
+                    }
+                    let Some((next_dir, next)) =
+                        open_dirs(grid, current).find(|(_, next)| *next != previous)
+                    else {
+                        // dead end
+                        break;
+                    };
+                    (previous, current, dir) = (current, next, next_dir);
+                    length += 1;
+                }
+            }
+        }
+
+        let start = index[&start_point(grid)];
+        let end = index[&end_point(grid)];
+        // the end is only reachable through one junction, and leaving that
+        // junction any other way would cut us off from the end for good
+        let into_end = (0..edges.len())
+            .filter(|from| edges[*from].iter().any(|(to, _)| *to == end))
+            .collect::<Vec<_>>();
+        if let [last] = into_end[..] {
+            edges[last].retain(|(to, _)| *to == end);
+        }
+
+        Graph { edges, start, end }
+    }
+
+    pub fn reaches_end(&self) -> bool {
+        let mut seen = vec![false; self.edges.len()];
+        let mut stack = vec![self.start];
+        while let Some(node) = stack.pop() {
+            if node == self.end {
+                return true;
+            }
+            if !std::mem::replace(&mut seen[node], true) {
+                stack.extend(self.edges[node].iter().map(|(next, _)| *next));
+            }
+        }
+        false
+    }
+
+    // the longest walk from start to end never visiting a junction twice,
+    // tracking the visited junctions as bits
+    pub fn longest_path(&self) -> Option<usize> {
+        self.longest_from(self.start, 1 << self.start)
+    }
+
+    fn longest_from(&self, node: usize, visited: u64) -> Option<usize> {
+        if node == self.end {
+            return Some(0);
+        }
+        self.edges[node]
+            .iter()
+            .filter(|(next, _)| visited & (1 << next) == 0)
+            .filter_map(|(next, length)| {
+                self.longest_from(*next, visited | (1 << next))
+                    .map(|rest| rest + length)
+            })
+            .max()
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_graph() {
+        let grid = parse_input(EXAMPLE).unwrap();
+        let slippy = Graph::new(&grid, true);
+        let dry = Graph::new(&grid, false);
+        // start, end and 7 junctions
+        assert_eq!(slippy.edges.len(), 9);
+        // from the start there's one corridor, 15 long, in either case
+        let lengths = |graph: &Graph| {
+            graph.edges[graph.start]
+                .iter()
+                .map(|(_, length)| *length)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(lengths(&slippy), vec![15]);
+        assert_eq!(lengths(&dry), vec![15]);
+        // without slopes every corridor can be walked back
+        let count = |graph: &Graph| graph.edges.iter().map(Vec::len).sum::<usize>();
+        assert!(count(&dry) > count(&slippy));
+        assert!(slippy.reaches_end() && dry.reaches_end());
+    }
+
+    #[test]
+    fn invalid() {
+        let error = |input| parse_input(input).unwrap_err().to_string();
+        assert_eq!(
+            error("###\n#.#\n#.#"),
+            "line 1, column 1: expected a path in the top row"
+        );
+        assert_eq!(
+            error("#.#\n#.#\n###"),
+            "line 3, column 1: expected a path in the bottom row"
+        );
+        // in an open field nearly every tile is a junction
+        let field = format!(
+            "#.{}\n{}{}.#",
+            "#".repeat(10),
+            format!("{}\n", ".".repeat(12)).repeat(8),
+            "#".repeat(10)
+        );
+        assert_eq!(
+            error(&field),
+            "line 10, column 13: expected at most 64 junctions, found 94"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+use std::ops::{Mul, Sub};
+
+// an exact num / den, kept reduced with a positive denominator - every
+// operation is checked, as wrapping would silently give wrong answers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fraction {
+    num: i128,
+    den: i128,
+}
+
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+fn checked(value: Option<i128>) -> i128 {
+    value.expect("fraction overflowed i128")
+}
+
+impl Fraction {
+    pub const ZERO: Fraction = Fraction { num: 0, den: 1 };
+
+    pub fn new(num: i128, den: i128) -> Self {
+        assert_ne!(den, 0, "zero denominator");
+        let divisor = gcd(num, den) * den.signum();
+        Fraction {
+            num: num / divisor,
+            den: den / divisor,
+        }
+    }
+}
+
+impl From<i128> for Fraction {
+    fn from(n: i128) -> Self {
+        Fraction { num: n, den: 1 }
+    }
+}
+
+impl Sub for Fraction {
+    type Output = Fraction;
+
+    fn sub(self, other: Fraction) -> Fraction {
+        // over the lcm of the denominators, to keep the numbers small
+        let divisor = gcd(self.den, other.den);
+        let (a, b) = (self.den / divisor, other.den / divisor);
+        let num = checked(self.num.checked_mul(b)).checked_sub(checked(other.num.checked_mul(a)));
+        Fraction::new(checked(num), checked(self.den.checked_mul(b)))
+    }
+}
+
+impl Mul for Fraction {
+    type Output = Fraction;
+
+    fn mul(self, other: Fraction) -> Fraction {
+        // cancel across first so the products are as small as they can be
+        let a = gcd(self.num, other.den).max(1);
+        let b = gcd(other.num, self.den).max(1);
+        Fraction::new(
+            checked((self.num / a).checked_mul(other.num / b)),
+            checked((self.den / b).checked_mul(other.den / a)),
+        )
+    }
+}
+
+impl Ord for Fraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // the denominators are positive, so cross multiplying keeps the order
+        checked(self.num.checked_mul(other.den)).cmp(&checked(other.num.checked_mul(self.den)))
+    }
+}
+
+impl PartialOrd for Fraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(num: i128, den: i128) -> Fraction {
+        Fraction::new(num, den)
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(f(2, 4), f(1, 2));
+        assert_eq!(f(1, -2), f(-1, 2));
+        assert_eq!(f(1, 2) - f(1, 3), f(1, 6));
+        assert_eq!(f(1, 6) - f(1, 6), Fraction::ZERO);
+        assert_eq!(f(2, 3) * f(-3, 4), f(-1, 2));
+        assert_eq!(f(6, 3), 2.into());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(f(1, 3) < f(1, 2));
+        assert!(f(-1, 2) < f(-1, 3));
+        assert!(f(7, 7) >= 1.into());
+        // bigger than an f64 can tell apart
+        let big = 200_000_000_000_000_000;
+        assert!(f(big * 3 + 1, 3) > big.into());
+    }
+}
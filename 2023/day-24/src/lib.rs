@@ -0,0 +1,76 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+mod fraction;
+mod part1;
+mod part2;
+
+// the test area for part 1, in both x and y
+const WINDOW_MIN: i128 = 200000000000000;
+const WINDOW_MAX: i128 = 400000000000000;
+
+type Vector = [i128; 3];
+
+#[derive(Debug)]
+pub struct Hailstone {
+    p: Vector,
+    v: Vector,
+}
+
+fn parse_vector(input: &str, vector: &str) -> Result<Vector, ParseError> {
+    let mut nums = vector
+        .split(',')
+        .map(|n| aoc_parse::parse(input, n.trim(), "an integer"));
+    let mut next = || {
+        nums.next().unwrap_or_else(|| {
+            let message = "expected 3 integers";
+            Err(ParseError::at(input, aoc_parse::end(vector), message))
+        })
+    };
+    Ok([next()?, next()?, next()?])
+}
+
+fn parse_input(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (p, v) = aoc_parse::split_once(input, line, " @ ")?;
+            Ok(Hailstone {
+                p: parse_vector(input, p)?,
+                v: parse_vector(input, v)?,
+            })
+        })
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(hailstones: &Self::Parsed<'_>) -> impl Display {
+        part1::get_answer(hailstones, WINDOW_MIN, WINDOW_MAX)
+    }
+
+    fn part2(hailstones: &Self::Parsed<'_>) -> impl Display {
+        part2::get_answer(hailstones)
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
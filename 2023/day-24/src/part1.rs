@@ -0,0 +1,147 @@
+use crate::fraction::Fraction;
+use crate::Hailstone;
+
+// a hailstone's path through x and y - z is ignored in part 1
+#[derive(Debug)]
+struct Path {
+    px: i128,
+    py: i128,
+    vx: i128,
+    vy: i128,
+    // the path as the line ax + by = c
+    a: i128,
+    b: i128,
+    c: i128,
+}
+
+impl Path {
+    fn new(px: i128, py: i128, vx: i128, vy: i128) -> Self {
+        Path {
+            px,
+            py,
+            vx,
+            vy,
+            a: vy,
+            b: -vx,
+            c: vy * px - vx * py,
+        }
+    }
+
+    // the line intersection from https://github.com/hyper-neutrino/advent-of-code/blob/main/2023/day24p1.py#L23,
+    // but with fractions, as f64 can't tell points apart this far from zero
+    fn intersection(&self, other: &Self, min: i128, max: i128) -> bool {
+        let (a1, b1, c1) = (self.a, self.b, self.c);
+        let (a2, b2, c2) = (other.a, other.b, other.c);
+        let det = a1 * b2 - a2 * b1;
+        if det == 0 {
+            // parallel lines
+            return false;
+        }
+
+        let x = Fraction::new(c1 * b2 - c2 * b1, det);
+        let y = Fraction::new(c2 * a1 - c1 * a2, det);
+        let window = Fraction::from(min)..=Fraction::from(max);
+        if window.contains(&x) && window.contains(&y) {
+            // the crossing must be in the future for both hailstones
+            let check = |hs: &Path| {
+                (x - hs.px.into()) * hs.vx.into() >= Fraction::ZERO
+                    && (y - hs.py.into()) * hs.vy.into() >= Fraction::ZERO
+            };
+            return check(self) && check(other);
+        }
+
+        false
+    }
+}
+
+impl From<&Hailstone> for Path {
+    fn from(hailstone: &Hailstone) -> Self {
+        Path::new(
+            hailstone.p[0],
+            hailstone.p[1],
+            hailstone.v[0],
+            hailstone.v[1],
+        )
+    }
+}
+
+pub fn get_answer(hailstones: &[Hailstone], window_min: i128, window_max: i128) -> usize {
+    let paths = hailstones.iter().map(Path::from).collect::<Vec<_>>();
+    paths
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| {
+            paths
+                .iter()
+                .skip(i + 1)
+                .filter(|b| a.intersection(b, window_min, window_max))
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EXAMPLE;
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let hailstones = crate::parse_input(EXAMPLE).unwrap();
+        assert_eq!(get_answer(&hailstones, 7, 27), 2);
+    }
+
+    #[test]
+    fn window_edge() {
+        // these cross exactly on the left edge of the window, at x = 2e14,
+        // but f64 puts it just outside
+        let a = Path::new(89688204121800, 258698562430829, 280, 285);
+        let b = Path::new(689816508689948, 162463947063342, -498, 212);
+        let (min, max) = (200000000000000, 400000000000000);
+        assert!(a.intersection(&b, min, max));
+        assert!(!intersection_f64(&a, &b, min as f64, max as f64));
+    }
+
+    // the original float version, which is fine on small inputs
+    fn crossing_f64(one: &Path, other: &Path) -> Option<(f64, f64)> {
+        let (a1, b1, c1) = (one.a as f64, one.b as f64, one.c as f64);
+        let (a2, b2, c2) = (other.a as f64, other.b as f64, other.c as f64);
+        if a1 * b2 == b1 * a2 {
+            return None;
+        }
+        let x = (c1 * b2 - c2 * b1) / (a1 * b2 - a2 * b1);
+        let y = (c2 * a1 - c1 * a2) / (a1 * b2 - a2 * b1);
+        Some((x, y))
+    }
+
+    fn intersection_f64(one: &Path, other: &Path, min: f64, max: f64) -> bool {
+        let Some((x, y)) = crossing_f64(one, other) else {
+            return false;
+        };
+        let check = |hs: &Path| {
+            (x - hs.px as f64) * hs.vx as f64 >= 0.0 && (y - hs.py as f64) * hs.vy as f64 >= 0.0
+        };
+        min <= x && x <= max && min <= y && y <= max && check(one) && check(other)
+    }
+
+    fn path() -> impl Strategy<Value = Path> {
+        (-50..50i128, -50..50i128, -5..5i128, -5..5i128)
+            .prop_map(|(px, py, vx, vy)| Path::new(px, py, vx, vy))
+    }
+
+    proptest! {
+        #[test]
+        fn matches_f64_on_small_inputs(
+            a in path(),
+            b in path(),
+            min in -60..60i128,
+            size in 0..60i128,
+        ) {
+            let max = min + size;
+            prop_assert_eq!(
+                a.intersection(&b, min, max),
+                intersection_f64(&a, &b, min as f64, max as f64)
+            );
+        }
+    }
+}
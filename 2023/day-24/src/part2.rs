@@ -0,0 +1,126 @@
+use crate::{Hailstone, Vector};
+
+fn sub(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: Vector, b: Vector) -> Vector {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+// The rock (P, V) hits hailstone i at some time t, so P + tV = p + tv, which
+// means (P - p) is parallel to (V - v) and (P - p) x (V - v) = 0. Expanding
+// that gives P x V - P x v - p x V + p x v = 0, and subtracting the same for
+// another hailstone j cancels the only non-linear term:
+//   P x (vj - vi) + (pj - pi) x V = pj x vj - pi x vi
+// which is 3 linear equations in the 6 unknowns, per pair of hailstones.
+fn pair_equations(i: &Hailstone, j: &Hailstone) -> [[i128; 7]; 3] {
+    let a = sub(j.v, i.v);
+    let b = sub(j.p, i.p);
+    let c = sub(cross(j.p, j.v), cross(i.p, i.v));
+    // unknowns are X, Y, Z, VX, VY, VZ
+    [
+        [0, a[2], -a[1], 0, -b[2], b[1], c[0]],
+        [-a[2], 0, a[0], b[2], 0, -b[0], c[1]],
+        [a[1], -a[0], 0, -b[1], b[0], 0, c[2]],
+    ]
+}
+
+// The answer is exact but the elimination isn't small: the minors of the 6x6
+// system reach ~10^50 with these inputs, far past i128 even as reduced
+// fractions. The solution itself is only ~10^15 though, so solving modulo a
+// prime bigger than twice that recovers it exactly, with every product of two
+// residues still fitting in an i128. A solution that's only right modulo the
+// prime wouldn't survive checking against the hailstones.
+const PRIME: i128 = (1 << 61) - 1;
+
+fn pow_mod(mut base: i128, mut exp: i128) -> i128 {
+    let mut result = 1;
+    base = base.rem_euclid(PRIME);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % PRIME;
+        }
+        base = base * base % PRIME;
+        exp >>= 1;
+    }
+    result
+}
+
+// gaussian elimination mod PRIME on rows of 6 coefficients and a right hand
+// side, None if the system is singular
+fn solve(mut rows: Vec<[i128; 7]>) -> Option<[i128; 6]> {
+    for row in rows.iter_mut() {
+        for n in row.iter_mut() {
+            *n = n.rem_euclid(PRIME);
+        }
+    }
+    for col in 0..6 {
+        let pivot = (col..6).find(|&row| rows[row][col] != 0)?;
+        rows.swap(col, pivot);
+        // scale the pivot row so the pivot is 1, using Fermat for the inverse
+        let inverse = pow_mod(rows[col][col], PRIME - 2);
+        for n in rows[col].iter_mut() {
+            *n = *n * inverse % PRIME;
+        }
+        let pivot_row = rows[col];
+        for (i, row) in rows.iter_mut().enumerate() {
+            let factor = row[col];
+            if i == col || factor == 0 {
+                continue;
+            }
+            for (n, pivot_n) in row.iter_mut().zip(pivot_row).skip(col) {
+                *n = (*n - factor * pivot_n).rem_euclid(PRIME);
+            }
+        }
+    }
+    // back to signed, taking anything over half way as negative
+    Some(std::array::from_fn(|i| match rows[i][6] {
+        n if n > PRIME / 2 => n - PRIME,
+        n => n,
+    }))
+}
+
+fn hits(p: Vector, v: Vector, hailstone: &Hailstone) -> bool {
+    cross(sub(p, hailstone.p), sub(v, hailstone.v)) == [0; 3]
+}
+
+// the rock's starting position and velocity
+fn throw(hailstones: &[Hailstone]) -> Option<(Vector, Vector)> {
+    // two pairs give the six equations, but some pairs may be degenerate
+    // (parallel hailstones), so keep trying until the system is solvable
+    (1..hailstones.len())
+        .flat_map(|j| (j + 1..hailstones.len()).map(move |k| (j, k)))
+        .find_map(|(j, k)| {
+            let mut rows = pair_equations(&hailstones[0], &hailstones[j]).to_vec();
+            rows.extend(pair_equations(&hailstones[0], &hailstones[k]));
+            let solution = solve(rows)?;
+            let p = [solution[0], solution[1], solution[2]];
+            let v = [solution[3], solution[4], solution[5]];
+            hailstones
+                .iter()
+                .all(|hailstone| hits(p, v, hailstone))
+                .then_some((p, v))
+        })
+}
+
+pub fn get_answer(hailstones: &[Hailstone]) -> i128 {
+    let (p, _) = throw(hailstones).expect("a rock that hits every hailstone");
+    p.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EXAMPLE;
+
+    #[test]
+    fn example_throw() {
+        let hailstones = crate::parse_input(EXAMPLE).unwrap();
+        assert_eq!(throw(&hailstones), Some(([24, 13, 10], [-3, 1, 2])));
+    }
+}
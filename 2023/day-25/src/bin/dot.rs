@@ -0,0 +1,10 @@
+use aoc_solution::Solution;
+use y2023_day_25::{to_dot, Day};
+
+// the wiring diagram as Graphviz - the three wires to cut stand out anyway
+// with a force directed layout, e.g. `neato -Tsvg`
+fn main() {
+    let input = &aoc_input::load_or_exit(2023, 25);
+    let connections = aoc_parse::or_exit(Day::parse(input), input);
+    println!("{}", to_dot(&connections));
+}
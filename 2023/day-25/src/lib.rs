@@ -0,0 +1,152 @@
+use rustworkx_core::Result;
+use rustworkx_core::{connectivity::stoer_wagner_min_cut, petgraph::graph::UnGraph};
+use std::collections::{HashMap, HashSet};
+
+use aoc_dot::Dot;
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
+
+// each component and the ones it's wired to, in one direction only
+pub type Connections<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+fn parse_input(input: &str) -> Result<Connections<'_>, ParseError> {
+    let mut connections = HashMap::new();
+
+    for line in input.trim().lines() {
+        let (name, others) = aoc_parse::split_once(input, line, ": ")?;
+        let others: Vec<&str> = others.split(' ').collect();
+        connections
+            .entry(name)
+            .and_modify(|entry: &mut Vec<&str>| entry.append(&mut others.clone()))
+            .or_insert(others.clone());
+    }
+
+    Ok(connections)
+}
+
+// the smallest set of wires to cut to split the components in two, as the
+// size of that cut and the components on one side
+fn min_cut<'a>(connections: &Connections<'a>) -> (usize, HashSet<&'a str>) {
+    let mut nodes = HashMap::new();
+    let mut graph = UnGraph::<&str, u32>::default();
+    for (name, others) in connections.iter() {
+        let a = *nodes.entry(*name).or_insert_with(|| graph.add_node(name));
+        for other in others {
+            let b = *nodes.entry(*other).or_insert_with(|| graph.add_node(other));
+            graph.add_edge(a, b, 1);
+        }
+    }
+
+    let min_cut_res: Result<Option<(usize, Vec<_>)>> = stoer_wagner_min_cut(&graph, |_| Ok(1));
+    let (min_cut, partition) = min_cut_res.unwrap().unwrap();
+    (
+        min_cut,
+        partition.into_iter().map(|node| graph[node]).collect(),
+    )
+}
+
+// the wiring diagram, with the two halves coloured and the cut wires in red
+pub fn to_dot(connections: &Connections) -> Dot {
+    let (_, partition) = min_cut(connections);
+    let mut names = connections.keys().copied().collect::<Vec<_>>();
+    names.sort();
+
+    let mut dot = Dot::graph("wiring");
+    let mut drawn = HashSet::new();
+    for name in &names {
+        for other in &connections[name] {
+            for node in [name, other] {
+                if drawn.insert(*node) {
+                    let colour = if partition.contains(node) {
+                        "lightblue"
+                    } else {
+                        "wheat"
+                    };
+                    dot.node(node, &[("style", "filled"), ("fillcolor", colour)]);
+                }
+            }
+            if partition.contains(name) != partition.contains(other) {
+                dot.edge(name, other, &[("color", "red"), ("penwidth", "3")]);
+            } else {
+                dot.edge(name, other, &[]);
+            }
+        }
+    }
+    dot
+}
+
+fn split_sizes(connections: &Connections) -> usize {
+    let total_nodes = connections
+        .iter()
+        .flat_map(|(k, v)| {
+            let mut vs = v.clone();
+            vs.push(k);
+            vs
+        })
+        .collect::<HashSet<_>>()
+        .len();
+
+    let (min_cut, partition) = min_cut(connections);
+    assert_eq!(min_cut, 3);
+
+    partition.len() * (total_nodes - partition.len())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed<'a> = Connections<'a>;
+
+    fn parse(input: &str) -> Result<Connections<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(connections: &Self::Parsed<'_>) -> impl Display {
+        split_sizes(connections)
+    }
+
+    // the last day only has the one puzzle
+    fn part2(_: &Self::Parsed<'_>) -> impl Display {
+        "Merry Christmas"
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    #[test]
+    fn dot() {
+        let dot = to_dot(&parse_input(EXAMPLE).unwrap()).to_string();
+        // the three wires from the puzzle
+        let cut = dot.lines().filter(|line| line.contains("red")).count();
+        assert_eq!(cut, 3);
+        assert!(dot.contains(r#""cmg" -- "bvb" [color="red", penwidth="3"];"#));
+        assert_eq!(
+            dot.lines()
+                .filter(|line| line.contains("fillcolor"))
+                .count(),
+            15
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+// what we know about one part's answer
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Known {
+    // the accepted answer, if we have one
+    pub answer: Option<String>,
+    // guesses the site has already rejected
+    pub wrong: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    KnownWrong,
+    Unknown,
+}
+
+impl Status {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Status::Pass | Status::Unknown)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Status::KnownWrong => write!(f, "WRONG, already rejected"),
+            Status::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        message: String,
+    },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswersError::Io { path, error } => {
+                write!(f, "couldn't read {}: {error}", path.display())
+            }
+            AnswersError::Parse { path, message } => {
+                write!(f, "invalid answers file {}: {message}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+// answers can be written as numbers or strings, but are compared as printed
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Number(i64),
+    Text(String),
+}
+
+impl From<Value> for String {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Number(n) => n.to_string(),
+            Value::Text(text) => text,
+        }
+    }
+}
+
+// either `part1 = 46065` or `part1 = { answer = 46065, wrong = [46000] }`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Entry {
+    Answer(Value),
+    Full {
+        answer: Option<Value>,
+        #[serde(default)]
+        wrong: Vec<Value>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Answers {
+    parts: HashMap<(u8, u8), Known>,
+}
+
+impl Answers {
+    // a [day-NN] table per day, with part1 and part2 keys
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let days = toml::from_str::<HashMap<String, HashMap<String, Entry>>>(input)
+            .map_err(|err| err.message().to_string())?;
+
+        let mut parts = HashMap::new();
+        for (day_key, entries) in days {
+            let day = day_key
+                .strip_prefix("day-")
+                .and_then(|day| day.parse().ok())
+                .ok_or(format!("expected a table like [day-07], got [{day_key}]"))?;
+            for (part_key, entry) in entries {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => {
+                        return Err(format!(
+                            "expected part1 or part2 in [{day_key}], got {part_key}"
+                        ))
+                    }
+                };
+                let known = match entry {
+                    Entry::Answer(answer) => Known {
+                        answer: Some(answer.into()),
+                        wrong: vec![],
+                    },
+                    Entry::Full { answer, wrong } => Known {
+                        answer: answer.map(String::from),
+                        wrong: wrong.into_iter().map(String::from).collect(),
+                    },
+                };
+                parts.insert((day, part), known);
+            }
+        }
+        Ok(Answers { parts })
+    }
+
+    // a missing file just means nothing is known yet
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AnswersError> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(input) => Answers::parse(&input).map_err(|message| AnswersError::Parse {
+                path: path.to_path_buf(),
+                message,
+            }),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(AnswersError::Io {
+                path: path.to_path_buf(),
+                error,
+            }),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Known> {
+        self.parts.get(&(day, part))
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Status {
+        let Some(known) = self.get(day, part) else {
+            return Status::Unknown;
+        };
+        if known.wrong.iter().any(|wrong| wrong == answer) {
+            return Status::KnownWrong;
+        }
+        match &known.answer {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.clone(),
+            },
+            None => Status::Unknown,
+        }
+    }
+}
+
+// kept with the inputs, as answers are only right for the matching input
+pub fn path(cache_dir: impl AsRef<Path>, year: u16) -> PathBuf {
+    cache_dir
+        .as_ref()
+        .join(year.to_string())
+        .join("answers.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+[day-07]
+part1 = 46065
+part2 = 14134
+
+[day-11]
+part1 = "hxbxxyzz"
+
+[day-23]
+part1 = 2030
+part2 = { answer = 6390, wrong = [2970] } # too low
+
+[day-24]
+part2 = { wrong = [1] }
+"#;
+
+    #[test]
+    fn check() {
+        let answers = Answers::parse(EXAMPLE).unwrap();
+        assert_eq!(answers.check(7, 1, "46065"), Status::Pass);
+        assert_eq!(
+            answers.check(7, 2, "14135"),
+            Status::Fail {
+                expected: "14134".to_string()
+            }
+        );
+        assert_eq!(answers.check(11, 1, "hxbxxyzz"), Status::Pass);
+        assert_eq!(answers.check(11, 2, "hxcaabcc"), Status::Unknown);
+        assert_eq!(answers.check(23, 2, "6390"), Status::Pass);
+        assert_eq!(answers.check(23, 2, "2970"), Status::KnownWrong);
+        assert_eq!(answers.check(24, 2, "1"), Status::KnownWrong);
+        assert_eq!(answers.check(24, 2, "2"), Status::Unknown);
+        assert_eq!(answers.check(25, 1, "1"), Status::Unknown);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Answers::parse("[day-seven]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day-07]\npart3 = 1").is_err());
+        assert!(Answers::parse("[day-07]\npart1 = [1]").is_err());
+        assert!(Answers::parse("[day-07\npart1 = 1").is_err());
+    }
+
+    #[test]
+    fn missing_file_is_empty() {
+        let answers = Answers::load("/no/such/answers.toml").unwrap();
+        assert_eq!(answers, Answers::default());
+    }
+
+    #[test]
+    fn repo_answers_parse() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        for year in [2015, 2023] {
+            let answers = Answers::load(path(&root, year)).unwrap();
+            assert!(answers.get(1, 2).is_some(), "{year}");
+        }
+    }
+}
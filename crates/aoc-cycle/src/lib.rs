@@ -0,0 +1,181 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+// the states from start on repeat every len steps
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    // the earliest step with the same state as step n
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+}
+
+// a state along with its hash, so most comparisons never look at the state
+struct Hashed<S> {
+    state: S,
+    hash: u64,
+}
+
+impl<S: Hash + Eq> Hashed<S> {
+    fn new(state: S) -> Self {
+        let mut hasher = DefaultHasher::new();
+        state.hash(&mut hasher);
+        Hashed {
+            hash: hasher.finish(),
+            state,
+        }
+    }
+
+    fn same(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.state == other.state
+    }
+}
+
+enum Walk<S> {
+    // got to the limit before anything repeated
+    Reached(S),
+    // the state steps in repeats len steps later
+    Repeated { state: S, steps: usize, len: usize },
+}
+
+// Brent's algorithm - the hare steps on one state at a time while the
+// tortoise jumps to it at each power of two, so only two states are ever kept
+fn walk<S, F>(initial: &S, step: &mut F, limit: usize) -> Walk<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = Hashed::new(initial.clone());
+    let mut hare = Hashed::new(step(initial));
+    let mut steps = 1;
+    let (mut power, mut len) = (1, 1);
+    loop {
+        if steps == limit {
+            return Walk::Reached(hare.state);
+        }
+        if tortoise.same(&hare) {
+            return Walk::Repeated {
+                state: hare.state,
+                steps,
+                len,
+            };
+        }
+        if power == len {
+            tortoise = Hashed::new(hare.state.clone());
+            power *= 2;
+            len = 0;
+        }
+        hare = Hashed::new(step(&hare.state));
+        steps += 1;
+        len += 1;
+    }
+}
+
+// where the states reached by repeatedly stepping from initial start to
+// repeat - this never returns if they don't
+pub fn find_cycle<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let Walk::Repeated { len, .. } = walk(&initial, &mut step, usize::MAX) else {
+        unreachable!("no limit to reach");
+    };
+    // with the hare len steps ahead, they first meet at the start of the cycle
+    let mut tortoise = Hashed::new(initial);
+    let mut hare = Hashed::new(tortoise.state.clone());
+    for _ in 0..len {
+        hare = Hashed::new(step(&hare.state));
+    }
+    let mut start = 0;
+    while !tortoise.same(&hare) {
+        tortoise = Hashed::new(step(&tortoise.state));
+        hare = Hashed::new(step(&hare.state));
+        start += 1;
+    }
+    Cycle { start, len }
+}
+
+// the state after n steps from initial, skipping round the cycle once one is
+// found so any n takes no longer than finding it
+pub fn nth_state<S, F>(initial: S, n: usize, mut step: F) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    if n == 0 {
+        return initial;
+    }
+    match walk(&initial, &mut step, n) {
+        Walk::Reached(state) => state,
+        Walk::Repeated {
+            mut state,
+            steps,
+            len,
+        } => {
+            for _ in 0..(n - steps) % len {
+                state = step(&state);
+            }
+            state
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, 3, ...
+    fn rho(n: &u32) -> u32 {
+        if *n < 6 {
+            n + 1
+        } else {
+            3
+        }
+    }
+
+    #[test]
+    fn cycle() {
+        let cycle = find_cycle(0, rho);
+        assert_eq!(cycle, Cycle { start: 3, len: 4 });
+        assert_eq!(cycle.index(2), 2);
+        assert_eq!(cycle.index(7), 3);
+        assert_eq!(cycle.index(1_000_000_000), 4);
+    }
+
+    #[test]
+    fn cycle_from_the_start() {
+        assert_eq!(find_cycle(0, |n| (n + 1) % 5), Cycle { start: 0, len: 5 });
+        assert_eq!(find_cycle('x', |c| *c), Cycle { start: 0, len: 1 });
+    }
+
+    #[test]
+    fn matches_stepping() {
+        let mut state = 0;
+        for n in 0..50 {
+            assert_eq!(nth_state(0, n, rho), state, "after {n} steps");
+            state = rho(&state);
+        }
+        assert_eq!(nth_state(0, 1_000_000_000, rho), 4);
+    }
+
+    #[test]
+    fn stops_at_n() {
+        // counting up never repeats, but only the first 100 steps are needed
+        let mut steps = 0;
+        let state = nth_state(0u64, 100, |n| {
+            steps += 1;
+            n + 1
+        });
+        assert_eq!((state, steps), (100, 100));
+    }
+}
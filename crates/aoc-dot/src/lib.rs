@@ -0,0 +1,111 @@
+use std::fmt::Display;
+
+// a Graphviz graph, built up node by node and edge by edge - render it with
+// something like `dot -Tsvg graph.dot > graph.svg`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dot {
+    name: String,
+    directed: bool,
+    lines: Vec<String>,
+}
+
+pub type Attrs<'a> = &'a [(&'a str, &'a str)];
+
+// ids and values are always quoted, so names like "broadcaster" or "11A" are fine
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn attrs(attrs: Attrs) -> String {
+    if attrs.is_empty() {
+        return String::new();
+    }
+    let attrs = attrs
+        .iter()
+        .map(|(key, value)| format!("{key}={}", quote(value)))
+        .collect::<Vec<_>>();
+    format!(" [{}]", attrs.join(", "))
+}
+
+impl Dot {
+    pub fn digraph(name: &str) -> Self {
+        Dot {
+            name: name.to_string(),
+            directed: true,
+            lines: vec![],
+        }
+    }
+
+    pub fn graph(name: &str) -> Self {
+        Dot {
+            name: name.to_string(),
+            directed: false,
+            lines: vec![],
+        }
+    }
+
+    pub fn node(&mut self, id: &str, node_attrs: Attrs) -> &mut Self {
+        self.lines
+            .push(format!("{}{};", quote(id), attrs(node_attrs)));
+        self
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, edge_attrs: Attrs) -> &mut Self {
+        let arrow = if self.directed { "->" } else { "--" };
+        self.lines.push(format!(
+            "{} {arrow} {}{};",
+            quote(from),
+            quote(to),
+            attrs(edge_attrs)
+        ));
+        self
+    }
+}
+
+impl Display for Dot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.directed { "digraph" } else { "graph" };
+        writeln!(f, "{kind} {} {{", quote(&self.name))?;
+        for line in &self.lines {
+            writeln!(f, "    {line}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digraph() {
+        let mut dot = Dot::digraph("day 20");
+        dot.node("broadcaster", &[("shape", "house")])
+            .node("a", &[])
+            .edge("broadcaster", "a", &[("label", "low")]);
+        assert_eq!(
+            dot.to_string(),
+            "digraph \"day 20\" {
+    \"broadcaster\" [shape=\"house\"];
+    \"a\";
+    \"broadcaster\" -> \"a\" [label=\"low\"];
+}"
+        );
+    }
+
+    #[test]
+    fn undirected_and_quoting() {
+        let mut dot = Dot::graph("g");
+        dot.edge(
+            "say \"hi\"",
+            "back\\slash",
+            &[("color", "red"), ("penwidth", "2")],
+        );
+        assert_eq!(
+            dot.to_string(),
+            "graph \"g\" {
+    \"say \\\"hi\\\"\" -- \"back\\\\slash\" [color=\"red\", penwidth=\"2\"];
+}"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir {
+    // clockwise, starting at north
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Dir::North => Dir::East,
+            Dir::East => Dir::South,
+            Dir::South => Dir::West,
+            Dir::West => Dir::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Dir::North => Dir::West,
+            Dir::East => Dir::North,
+            Dir::South => Dir::East,
+            Dir::West => Dir::South,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Dir::North => Dir::South,
+            Dir::East => Dir::West,
+            Dir::South => Dir::North,
+            Dir::West => Dir::East,
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        self == Dir::East || self == Dir::West
+    }
+
+    // y grows downwards, so north is -1
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Dir::North => (0, -1),
+            Dir::East => (1, 0),
+            Dir::South => (0, 1),
+            Dir::West => (-1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.turn_left().turn_left(), dir.reverse());
+            assert_eq!(dir.reverse().reverse(), dir);
+        }
+        assert_eq!(Dir::North.turn_right(), Dir::East);
+        assert_eq!(Dir::North.turn_left(), Dir::West);
+    }
+
+    #[test]
+    fn offsets() {
+        for dir in Dir::ALL {
+            let (dx, dy) = dir.offset();
+            let (rx, ry) = dir.reverse().offset();
+            assert_eq!((dx + rx, dy + ry), (0, 0));
+            assert_eq!(dir.is_horizontal(), dy == 0);
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use crate::{Dir, Point};
+use aoc_parse::ParseError;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "cells don't fit the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // one row per (trimmed) line, mapping each char to a cell
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let lines = input.trim().lines().map(str::trim_end).collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let cells = lines
+            .iter()
+            .flat_map(|line| {
+                assert_eq!(line.chars().count(), width, "ragged grid line {line:?}");
+                line.chars()
+            })
+            .map(&mut f)
+            .collect();
+        Grid::new(width, lines.len(), cells)
+    }
+
+    // as parse, but f can reject chars, and ragged lines are an error too
+    pub fn try_parse(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let lines = input.trim().lines().map(str::trim_end).collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in &lines {
+            if line.chars().count() != width {
+                let message = format!("expected {width} cells in each row");
+                return Err(ParseError::at(input, line, message));
+            }
+            cells.extend(aoc_parse::cells(input, line, &mut f)?);
+        }
+        Ok(Grid::new(width, lines.len(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero, but an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, mut f: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find_map(|(point, cell)| f(cell).then_some(point))
+    }
+
+    // one step in the given direction, None if that leaves the grid
+    pub fn step(&self, point: Point, dir: Dir) -> Option<Point> {
+        point.step(dir).filter(|next| self.contains(*next))
+    }
+
+    // north, east, south, west - skipping any outside the grid
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(point, dir))
+    }
+
+    // the 4 neighbours plus diagonals, clockwise from north
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        [
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+        ]
+        .into_iter()
+        .filter_map(move |offset| point.offset(offset))
+        .filter(|next| self.contains(*next))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    // rows become columns, so (x, y) moves to (y, x)
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    // the first column, read bottom up, becomes the first row
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).rev().cloned())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    // the last column, read top down, becomes the first row
+    pub fn rotate_anticlockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse(
+            "
+abc
+def
+",
+            |ch| ch,
+        )
+    }
+
+    #[test]
+    fn parse() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(0, 0)], 'a');
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.position(|ch| *ch == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+
+        let digits = Grid::parse("12\n34", |ch| ch.to_digit(10).unwrap());
+        assert_eq!(digits.iter().map(|(_, n)| n).sum::<u32>(), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "ragged")]
+    fn parse_ragged() {
+        Grid::parse("abc\nde", |ch| ch);
+    }
+
+    #[test]
+    fn try_parse() {
+        let digits = Grid::try_parse("12\n34", |ch| ch.to_digit(10));
+        assert_eq!(digits.map(|grid| grid.width()), Ok(2));
+        let error = Grid::try_parse("12\n3x", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: unexpected 'x'");
+        let error = Grid::try_parse("abc\nde", Some).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected 3 cells in each row"
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = example();
+        assert_eq!(
+            grid.neighbours(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours(Point::new(1, 1)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(2, 1), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+        assert_eq!(
+            grid.neighbours8(Point::new(1, 1))
+                .map(|point| grid[point])
+                .collect::<String>(),
+            "bcfda"
+        );
+    }
+
+    #[test]
+    fn step() {
+        let grid = example();
+        assert_eq!(
+            grid.step(Point::new(2, 0), Dir::South),
+            Some(Point::new(2, 1))
+        );
+        assert_eq!(grid.step(Point::new(2, 0), Dir::East), None);
+        assert_eq!(grid.step(Point::new(2, 0), Dir::North), None);
+    }
+
+    #[test]
+    fn mutate() {
+        let mut grid = example();
+        grid[Point::new(1, 0)] = '#';
+        *grid.get_mut(Point::new(2, 1)).unwrap() = '#';
+        assert_eq!(grid.get_mut(Point::new(3, 1)), None);
+        assert_eq!(grid.to_string(), "a#c\nde#");
+    }
+
+    #[test]
+    fn transform() {
+        let grid = example();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn map_and_display() {
+        let grid = example().map(|ch| ch.to_ascii_uppercase());
+        assert_eq!(grid.to_string(), "ABC\nDEF");
+        let filled = Grid::filled(2, 2, 0);
+        assert_eq!(filled.to_string(), "00\n00");
+    }
+}
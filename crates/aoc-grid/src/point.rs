@@ -0,0 +1,55 @@
+use crate::Dir;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+
+    // one step in the given direction, None if that would go below zero
+    pub fn step(self, dir: Dir) -> Option<Self> {
+        self.offset(dir.offset())
+    }
+
+    pub fn offset(self, (dx, dy): (isize, isize)) -> Option<Self> {
+        Some(Point {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point { x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step() {
+        let point = Point::new(0, 1);
+        assert_eq!(point.step(Dir::North), Some(Point::new(0, 0)));
+        assert_eq!(point.step(Dir::East), Some(Point::new(1, 1)));
+        assert_eq!(point.step(Dir::South), Some(Point::new(0, 2)));
+        assert_eq!(point.step(Dir::West), None);
+    }
+
+    #[test]
+    fn manhattan_distance() {
+        assert_eq!(Point::new(1, 6).manhattan_distance(Point::new(5, 11)), 9);
+        assert_eq!(Point::new(5, 11).manhattan_distance(Point::new(1, 6)), 9);
+    }
+}
@@ -0,0 +1,300 @@
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "aoc-input (rust advent of code solutions)";
+
+#[derive(Debug)]
+pub enum InputError {
+    // not cached and no session token to fetch it with
+    Missing {
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Fetch {
+        url: String,
+        message: String,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { year, day, path } => write!(
+                f,
+                "no input for {year} day {day} at {}\n\
+                 save it from {AOC_URL}/{year}/day/{day}/input, \
+                 or set AOC_SESSION to your session cookie to download it",
+                path.display()
+            ),
+            InputError::Io { path, error } => {
+                write!(f, "couldn't read {}: {error}", path.display())
+            }
+            InputError::Fetch { url, message } => write!(f, "couldn't download {url}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[derive(Debug, Clone)]
+pub struct Loader {
+    cache_dir: PathBuf,
+    session: Option<String>,
+    base_url: String,
+}
+
+impl Loader {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Loader {
+            cache_dir: cache_dir.into(),
+            session: None,
+            base_url: AOC_URL.to_string(),
+        }
+    }
+
+    // AOC_CACHE_DIR overrides the cache dir, AOC_SESSION enables downloads
+    pub fn from_env() -> Self {
+        let cache_dir = std::env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(default_cache_dir);
+        let loader = Loader::new(cache_dir);
+        match std::env::var("AOC_SESSION") {
+            Ok(session) if !session.trim().is_empty() => loader.with_session(session.trim()),
+            _ => loader,
+        }
+    }
+
+    pub fn with_cache_dir(self, cache_dir: impl Into<PathBuf>) -> Self {
+        Loader {
+            cache_dir: cache_dir.into(),
+            ..self
+        }
+    }
+
+    pub fn with_session(self, session: impl Into<String>) -> Self {
+        Loader {
+            session: Some(session.into()),
+            ..self
+        }
+    }
+
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Self {
+        Loader {
+            base_url: base_url.into(),
+            ..self
+        }
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    // laid out like the repo, so the cache dir can simply be the repo root
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day-{day:02}"))
+            .join("input.txt")
+    }
+
+    // the cached input, downloading and caching it first if we have a session
+    pub fn load(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(error) if error.kind() != ErrorKind::NotFound => {
+                return Err(InputError::Io { path, error })
+            }
+            Err(_) => {}
+        }
+
+        let Some(session) = &self.session else {
+            return Err(InputError::Missing { year, day, path });
+        };
+        let input = fetch(&self.base_url, year, day, session)?;
+        let io_error = |error| InputError::Io {
+            path: path.clone(),
+            error,
+        };
+        fs::create_dir_all(path.parent().expect("input path has a parent")).map_err(io_error)?;
+        fs::write(&path, &input).map_err(io_error)?;
+        Ok(input)
+    }
+}
+
+// the repo root, where each day's input.txt lives next to its code
+pub fn default_cache_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+pub fn load_file(path: impl AsRef<Path>) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|error| InputError::Io {
+        path: path.as_ref().to_path_buf(),
+        error,
+    })
+}
+
+// for the day binaries - prints the error and exits rather than panicking
+pub fn load_or_exit(year: u16, day: u8) -> String {
+    Loader::from_env().load(year, day).unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        std::process::exit(1);
+    })
+}
+
+pub fn fetch(base_url: &str, year: u16, day: u8, session: &str) -> Result<String, InputError> {
+    let url = format!("{base_url}/{year}/day/{day}/input");
+    let fetch_error = |message: String| InputError::Fetch {
+        url: url.clone(),
+        message,
+    };
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={session}"))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|error| match error {
+            ureq::Error::Status(400, _) => {
+                fetch_error("400 bad request, is the session token still valid?".to_string())
+            }
+            ureq::Error::Status(404, _) => {
+                fetch_error("404 not found, is the puzzle unlocked yet?".to_string())
+            }
+            error => fetch_error(error.to_string()),
+        })?;
+    response
+        .into_string()
+        .map_err(|error| fetch_error(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    // a one-shot stand-in for the AoC server, returning the request it received
+    fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                request.push_str(&line);
+            }
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (base_url, handle)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_with_session_and_caches() {
+        let (base_url, server) = serve_once("200 OK", "1\n2\n3\n");
+        let cache_dir = temp_dir("fetch");
+        let loader = Loader::new(&cache_dir)
+            .with_session("abc123")
+            .with_base_url(base_url);
+
+        assert_eq!(loader.load(2023, 5).unwrap(), "1\n2\n3\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/5/input "));
+        assert!(request.contains("session=abc123"));
+
+        // second load comes from the cache, as the server has gone
+        assert_eq!(
+            fs::read_to_string(cache_dir.join("2023/day-05/input.txt")).unwrap(),
+            "1\n2\n3\n"
+        );
+        assert_eq!(loader.load(2023, 5).unwrap(), "1\n2\n3\n");
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn bad_session() {
+        let (base_url, server) = serve_once("400 Bad Request", "Puzzle inputs differ by user.");
+        let cache_dir = temp_dir("bad-session");
+        let loader = Loader::new(&cache_dir)
+            .with_session("expired")
+            .with_base_url(base_url);
+
+        let error = loader.load(2023, 1).unwrap_err().to_string();
+        server.join().unwrap();
+        assert!(error.contains("session token"), "{error}");
+        assert!(!cache_dir.join("2023/day-01/input.txt").exists());
+    }
+
+    #[test]
+    fn missing_without_session() {
+        let cache_dir = temp_dir("missing");
+        let error = Loader::new(&cache_dir).load(2015, 25).unwrap_err();
+        assert!(matches!(
+            error,
+            InputError::Missing {
+                year: 2015,
+                day: 25,
+                ..
+            }
+        ));
+        let message = error.to_string();
+        assert!(message.contains("2015/day-25/input.txt"), "{message}");
+        assert!(
+            message.contains("https://adventofcode.com/2015/day/25/input"),
+            "{message}"
+        );
+        assert!(message.contains("AOC_SESSION"), "{message}");
+    }
+
+    #[test]
+    fn missing_file() {
+        let error = load_file("/no/such/input.txt").unwrap_err().to_string();
+        assert!(
+            error.starts_with("couldn't read /no/such/input.txt"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn reads_the_cache() {
+        let cache_dir = temp_dir("cache");
+        let loader = Loader::new(&cache_dir);
+        fs::create_dir_all(cache_dir.join("2023/day-01")).unwrap();
+        fs::write(loader.path(2023, 1), "1abc2\n").unwrap();
+        assert_eq!(loader.load(2023, 1).unwrap(), "1abc2\n");
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn default_cache_is_the_repo() {
+        // going by the workspace manifest, as the inputs aren't always there
+        let manifest = fs::read_to_string(default_cache_dir().join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("[workspace]"));
+    }
+}
@@ -0,0 +1,221 @@
+use std::str::FromStr;
+
+use crate::ParseError;
+
+// works along part of the input, each step taking what it expects off the
+// front or failing with where it went wrong in the whole input
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    // at the start of part, which must be a slice of the input
+    pub fn new(input: &'a str, part: &'a str) -> Self {
+        Cursor { input, rest: part }
+    }
+
+    // whatever hasn't been parsed yet
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    // an error pointing at the rest of the line
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.input, self.line(), message)
+    }
+
+    // saying what should have come next and what did instead
+    pub fn expected(&self, what: &str) -> ParseError {
+        match self.line() {
+            "" => self.error(format!("expected {what}")),
+            found => self.error(format!("expected {what}, found '{found}'")),
+        }
+    }
+
+    fn line(&self) -> &'a str {
+        self.rest.lines().next().unwrap_or(&self.rest[..0])
+    }
+
+    fn take(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        taken
+    }
+
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        if self.optional(tag) {
+            Ok(())
+        } else {
+            Err(self.expected(&format!("'{tag}'")))
+        }
+    }
+
+    // whether the tag was there, taking it if it was
+    pub fn optional(&mut self, tag: &str) -> bool {
+        match self.rest.strip_prefix(tag) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // the value for whichever of the tags comes next
+    pub fn one_of<T: Clone>(&mut self, options: &[(&str, T)]) -> Result<T, ParseError> {
+        options
+            .iter()
+            .find(|(tag, _)| self.optional(tag))
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| {
+                let tags = options
+                    .iter()
+                    .map(|(tag, _)| format!("'{tag}'"))
+                    .collect::<Vec<_>>();
+                self.expected(&tags.join(" or "))
+            })
+    }
+
+    // a name, made of letters and digits
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        let len = self
+            .rest
+            .find(|ch: char| !ch.is_ascii_alphanumeric())
+            .unwrap_or(self.rest.len());
+        if len == 0 {
+            return Err(self.expected("a name"));
+        }
+        Ok(self.take(len))
+    }
+
+    // a number, which may have a sign
+    pub fn int<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let sign = usize::from(self.rest.starts_with(['-', '+']));
+        let len = self.rest[sign..]
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign);
+        if len == 0 {
+            return Err(self.expected("a number"));
+        }
+        let number = self.take(sign + len);
+        crate::parse(self.input, number, "a number")
+    }
+
+    // everything up to the tag, taking the tag too
+    pub fn until(&mut self, tag: &str) -> Result<&'a str, ParseError> {
+        let len = self
+            .line()
+            .find(tag)
+            .ok_or_else(|| self.expected(&format!("'{tag}'")))?;
+        let before = self.take(len);
+        self.take(tag.len());
+        Ok(before)
+    }
+
+    // one or more items with the separator between them
+    pub fn list<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+        while self.optional(separator) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    // key<separator>value, key<separator>value, ... as in
+    // "trees: 2, cars: 1" or "capacity -1, flavor 6"
+    pub fn fields<T>(
+        &mut self,
+        separator: &str,
+        mut value: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<(&'a str, T)>, ParseError> {
+        self.list(", ", |cursor| {
+            let key = cursor.word()?;
+            cursor.tag(separator)?;
+            Ok((key, value(cursor)?))
+        })
+    }
+
+    // fails if there's anything left on the line
+    pub fn end(&self) -> Result<(), ParseError> {
+        match self.line() {
+            "" => Ok(()),
+            found => Err(self.error(format!("unexpected '{found}'"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps() {
+        let input = "Sue 12: cars: -2, trees: 9";
+        let mut cursor = Cursor::new(input, input);
+        cursor.tag("Sue ").unwrap();
+        assert_eq!(cursor.int::<u16>(), Ok(12));
+        cursor.tag(": ").unwrap();
+        assert_eq!(
+            cursor.fields(": ", Cursor::int::<i8>),
+            Ok(vec![("cars", -2), ("trees", 9)])
+        );
+        assert!(cursor.end().is_ok());
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn choices() {
+        let input = "lose 57 -> b";
+        let mut cursor = Cursor::new(input, input);
+        assert_eq!(cursor.one_of(&[("gain", 1), ("lose", -1)]), Ok(-1));
+        assert_eq!(cursor.until(" -> "), Ok(" 57"));
+        assert_eq!(cursor.rest(), "b");
+        assert!(!cursor.optional("c"));
+        let error = cursor.one_of(&[("x", 1), ("y", 2)]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 12: expected 'x' or 'y', found 'b'"
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let input = "a = (b, c)\nd = (e f)";
+        let (_, line) = input.split_once('\n').unwrap();
+        let mut cursor = Cursor::new(input, line);
+        cursor.word().unwrap();
+        cursor.tag(" = (").unwrap();
+        let result = cursor
+            .list(", ", Cursor::word)
+            .map(|_| ())
+            .and(cursor.end());
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 7: unexpected ' f)'"
+        );
+        assert_eq!(
+            cursor.int::<u8>().unwrap_err().to_string(),
+            "line 2, column 7: expected a number, found ' f)'"
+        );
+        let mut cursor = Cursor::new(input, &line[9..]);
+        assert_eq!(
+            cursor.word().unwrap_err().to_string(),
+            "line 2, column 10: expected a name"
+        );
+        let mut cursor = Cursor::new("300", "300");
+        assert_eq!(
+            cursor.int::<u8>().unwrap_err().to_string(),
+            "line 1, column 1: expected a number, found '300'"
+        );
+    }
+}
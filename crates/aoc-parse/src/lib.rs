@@ -0,0 +1,279 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+mod cursor;
+
+pub use cursor::Cursor;
+
+// what's wrong with a puzzle input and where, with lines and columns from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    // how many chars the problem covers, at least one
+    pub len: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    // at part of the input, which must be a slice of it - an empty slice
+    // points just past whatever came before it
+    pub fn at(input: &str, part: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + part.len() <= input.len())
+            .expect("part to be a slice of the input");
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            len: part.chars().take_while(|ch| *ch != '\n').count().max(1),
+            message: message.into(),
+        }
+    }
+
+    // an error from parsing part of the input on its own, moved to where that
+    // part is in the whole input
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let start = ParseError::at(input, &part[..0], "");
+        ParseError {
+            line: start.line + self.line - 1,
+            column: if self.line == 1 {
+                start.column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+
+    // the message along with the line it's about, marking where on the line
+    pub fn diagnostic(&self, input: &str) -> String {
+        let text = input.lines().nth(self.line - 1).unwrap_or("");
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        format!(
+            "error: {}\n{margin}--> line {}, column {}\n{margin} |\n{number} | {text}\n{margin} | {}{}",
+            self.message,
+            self.line,
+            self.column,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len),
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// part of the input as a T, or an error saying what it should have been
+pub fn parse<T: FromStr>(input: &str, part: &str, expected: &str) -> Result<T, ParseError> {
+    part.parse().map_err(|_| {
+        let message = if part.is_empty() {
+            format!("expected {expected}")
+        } else {
+            format!("expected {expected}, found '{part}'")
+        };
+        ParseError::at(input, part, message)
+    })
+}
+
+// the parts before and after the separator, which must be there
+pub fn split_once<'a>(
+    input: &str,
+    part: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    part.split_once(separator)
+        .ok_or_else(|| ParseError::at(input, part, format!("expected '{separator}'")))
+}
+
+// what follows the prefix, which must be there
+pub fn strip_prefix<'a>(input: &str, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    part.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, part, format!("expected '{prefix}'")))
+}
+
+// a row of cells, one per char, failing at the first char that isn't one
+pub fn cells<T>(
+    input: &str,
+    line: &str,
+    mut f: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<T>, ParseError> {
+    line.char_indices()
+        .map(|(i, ch)| {
+            f(ch).ok_or_else(|| {
+                let found = &line[i..i + ch.len_utf8()];
+                ParseError::at(input, found, format!("unexpected '{ch}'"))
+            })
+        })
+        .collect()
+}
+
+// the empty slice at the end of part, for pointing out anything missing
+pub fn end(part: &str) -> &str {
+    &part[part.len()..]
+}
+
+// each line of the trimmed part, parsed from start to end
+pub fn lines<'a, T, C: FromIterator<T>>(
+    input: &'a str,
+    part: &'a str,
+    mut line: impl FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+) -> Result<C, ParseError> {
+    part.trim()
+        .lines()
+        .map(|text| {
+            let mut cursor = Cursor::new(input, text);
+            let item = line(&mut cursor)?;
+            cursor.end()?;
+            Ok(item)
+        })
+        .collect()
+}
+
+// each blank-line separated block of part, such as a grid, parsed as if it
+// were the whole input with any error moved to where the block is
+pub fn blocks<'a, T, C: FromIterator<T>>(
+    input: &str,
+    part: &'a str,
+    mut block: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<C, ParseError> {
+    part.trim()
+        .split("\n\n")
+        .map(|text| block(text).map_err(|error| error.within(input, text)))
+        .collect()
+}
+
+// says what was being parsed when something went wrong, innermost first
+pub trait Context {
+    fn context(self, what: &str) -> Self;
+}
+
+impl<T> Context for Result<T, ParseError> {
+    fn context(self, what: &str) -> Self {
+        self.map_err(|error| ParseError {
+            message: format!("{} in {what}", error.message),
+            ..error
+        })
+    }
+}
+
+// the parsed value, or the diagnostic and an exit for the day binaries
+pub fn or_exit<T>(result: Result<T, ParseError>, input: &str) -> T {
+    result.unwrap_or_else(|error| {
+        eprintln!("{}", error.diagnostic(input));
+        std::process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position() {
+        let input = "1,0,1~1,2,1\n0,x,2~2,0,2\n";
+        let x = &input[14..15];
+        let error = parse::<u16>(input, x, "a coordinate").unwrap_err();
+        assert_eq!((error.line, error.column, error.len), (2, 3, 1));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a coordinate, found 'x'"
+        );
+        assert_eq!(
+            error.diagnostic(input),
+            "error: expected a coordinate, found 'x'
+ --> line 2, column 3
+  |
+2 | 0,x,2~2,0,2
+  |   ^"
+        );
+    }
+
+    #[test]
+    fn missing() {
+        let input = "a -> b\nc";
+        let (_, line) = input.split_once('\n').unwrap();
+        let error = split_once(input, line, " -> ").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected ' -> '");
+        let error = ParseError::at(input, end(line), "expected a target");
+        assert_eq!((error.line, error.column, error.len), (2, 2, 1));
+    }
+
+    #[test]
+    fn underlines() {
+        let input = "turn on 0,0 through 999,999\nturn up 1,1 through 2,2";
+        let error = ParseError::at(input, &input[33..35], "unknown action 'up'");
+        assert!(error.diagnostic(input).ends_with("\n  |      ^^"));
+    }
+
+    #[test]
+    fn moved() {
+        let input = "#.\n..\n\n.#\n#x";
+        let block = &input[7..];
+        let error = ParseError::at(block, &block[4..5], "unexpected 'x'");
+        assert_eq!(error.to_string(), "line 2, column 2: unexpected 'x'");
+        let error = error.within(input, block);
+        assert_eq!(error.to_string(), "line 5, column 2: unexpected 'x'");
+        assert!(error.diagnostic(input).ends_with("5 | #x\n  |  ^"));
+    }
+
+    #[test]
+    fn row() {
+        let input = "#.#\n.x.";
+        let (_, line) = input.split_once('\n').unwrap();
+        let cell = |ch| (ch != 'x').then_some(ch == '#');
+        assert_eq!(cells(input, &input[..3], cell), Ok(vec![true, false, true]));
+        let error = cells(input, line, cell).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: unexpected 'x'");
+    }
+
+    #[test]
+    fn every_line() {
+        let input = "\na: 1\nb: 2\nc 3\n";
+        fn pair<'a>(cursor: &mut Cursor<'a>) -> Result<(&'a str, u8), ParseError> {
+            let key = cursor.word()?;
+            cursor.tag(": ")?;
+            Ok((key, cursor.int()?))
+        }
+        let pairs: Result<Vec<_>, _> = lines(input, &input[..10], pair);
+        assert_eq!(pairs, Ok(vec![("a", 1), ("b", 2)]));
+        let error = lines::<_, Vec<_>>(input, input, pair)
+            .context("a pair")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 2: expected ': ', found ' 3' in a pair"
+        );
+        let error = lines::<_, Vec<_>>(input, input, |cursor| cursor.word()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: unexpected ': 1'");
+    }
+
+    #[test]
+    fn every_block() {
+        let input = "#.\n..\n\n.#\n#x";
+        let grids: Result<Vec<_>, _> = blocks(input, input, |block| {
+            lines::<_, Vec<_>>(block, block, |cursor| {
+                let row = cursor.rest();
+                cursor.tag(row)?;
+                cells(block, row, |ch| (ch != 'x').then_some(ch == '#'))
+            })
+        });
+        assert_eq!(
+            grids.unwrap_err().to_string(),
+            "line 5, column 2: unexpected 'x'"
+        );
+    }
+}
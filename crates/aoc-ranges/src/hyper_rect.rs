@@ -0,0 +1,80 @@
+use crate::{len, Bound};
+use std::ops::{Mul, Range};
+
+// a box with a range along each of N axes, like the x, m, a and s ratings of
+// a part - the values in it are every combination of one from each
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperRect<T, const N: usize> {
+    pub axes: [Range<T>; N],
+}
+
+impl<T: Bound, const N: usize> HyperRect<T, N> {
+    pub fn new(axes: [Range<T>; N]) -> Self {
+        HyperRect { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|range| range.is_empty())
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(range, value)| range.contains(&value))
+    }
+
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let axes = std::array::from_fn(|i| {
+            let (a, b) = (&self.axes[i], &other.axes[i]);
+            a.start.max(b.start)..a.end.min(b.end)
+        });
+        Some(HyperRect { axes }).filter(|rect| !rect.is_empty())
+    }
+
+    // the part with the axis below at, and the part with it from at up
+    pub fn split(&self, axis: usize, at: T) -> (Option<Self>, Option<Self>) {
+        let range = &self.axes[axis];
+        let mut below = self.clone();
+        below.axes[axis] = range.start..range.end.min(at);
+        let mut above = self.clone();
+        above.axes[axis] = range.start.max(at)..range.end;
+        (
+            Some(below).filter(|rect| !rect.is_empty()),
+            Some(above).filter(|rect| !rect.is_empty()),
+        )
+    }
+}
+
+impl<T: Bound + Mul<Output = T>, const N: usize> HyperRect<T, N> {
+    // how many values are in the box
+    pub fn volume(&self) -> T {
+        self.axes
+            .iter()
+            .map(len)
+            .reduce(|volume, len| volume * len)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split() {
+        let rect = HyperRect::new([1..4001u64, 1..4001, 1..4001, 1..4001]);
+        assert_eq!(rect.volume(), 4000u64.pow(4));
+        // x < 1416
+        let (below, above) = rect.split(0, 1416);
+        let (below, above) = (below.unwrap(), above.unwrap());
+        assert_eq!(below.axes[0], 1..1416);
+        assert_eq!(above.axes[0], 1416..4001);
+        assert_eq!(below.volume() + above.volume(), rect.volume());
+        assert!(below.contains([1415, 1, 1, 1]));
+        assert!(!below.contains([1416, 1, 1, 1]));
+        // splitting at an edge leaves nothing on one side
+        assert_eq!(below.split(0, 1), (None, Some(below.clone())));
+        assert_eq!(below.intersect(&above), None);
+    }
+}
@@ -0,0 +1,21 @@
+mod hyper_rect;
+mod range_set;
+
+pub use hyper_rect::HyperRect;
+pub use range_set::RangeSet;
+
+use std::ops::{Add, Range, Sub};
+
+// anything ranges can be made of - unsigned is fine, nothing goes below a
+// start that's already there
+pub trait Bound: Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>> Bound for T {}
+
+fn len<T: Bound>(range: &Range<T>) -> T {
+    if range.end > range.start {
+        range.end - range.start
+    } else {
+        T::default()
+    }
+}
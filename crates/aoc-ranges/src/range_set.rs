@@ -0,0 +1,194 @@
+use crate::{len, Bound};
+use std::ops::Range;
+
+// a set of values kept as sorted, non-overlapping, non-touching ranges
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Bound> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // how many values are in the set
+    pub fn count(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::default(), |count, range| count + len(range))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // everything overlapping or touching the new range gets merged into it
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = self.ranges[first..last].iter().fold(range, |merged, r| {
+            merged.start.min(r.start)..merged.end.max(r.end)
+        });
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersect(&self, other: &Self) -> Self {
+        self.ranges
+            .iter()
+            .flat_map(|a| {
+                other
+                    .ranges
+                    .iter()
+                    .map(|b| a.start.max(b.start)..a.end.min(b.end))
+            })
+            .collect()
+    }
+
+    pub fn subtract(&self, other: &Self) -> Self {
+        let mut rest = self.ranges.clone();
+        for b in &other.ranges {
+            rest = rest
+                .into_iter()
+                .flat_map(|a| [a.start..a.end.min(b.start), a.start.max(b.end)..a.end])
+                .filter(|range| !range.is_empty())
+                .collect();
+        }
+        RangeSet { ranges: rest }
+    }
+
+    // the values below at, and the values from at up
+    pub fn split(&self, at: T) -> (Self, Self) {
+        let below = self
+            .ranges
+            .iter()
+            .map(|range| range.start..range.end.min(at))
+            .collect();
+        let above = self
+            .ranges
+            .iter()
+            .map(|range| range.start.max(at)..range.end)
+            .collect();
+        (below, above)
+    }
+
+    // the values in source moved along to start at dest instead, along with
+    // the values that weren't in source and so stay put
+    pub fn map(&self, source: Range<T>, dest: T) -> (Self, Self) {
+        let source = RangeSet::from_iter([source]);
+        let Some(start) = source.min() else {
+            return (RangeSet::new(), self.clone());
+        };
+        let moved = self
+            .intersect(&source)
+            .ranges
+            .into_iter()
+            .map(|range| range.start - start + dest..range.end - start + dest)
+            .collect();
+        (moved, self.subtract(&source))
+    }
+}
+
+impl<T: Bound> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn set(ranges: &[(u32, u32)]) -> RangeSet<u32> {
+        ranges.iter().map(|&(start, end)| start..end).collect()
+    }
+
+    #[test]
+    fn insert_merges() {
+        let mut s = set(&[(1, 3), (7, 9)]);
+        s.insert(3..4);
+        assert_eq!(s, set(&[(1, 4), (7, 9)]));
+        s.insert(5..5);
+        assert_eq!(s, set(&[(1, 4), (7, 9)]));
+        s.insert(2..8);
+        assert_eq!(s, set(&[(1, 9)]));
+        assert_eq!(s.count(), 8);
+    }
+
+    #[test]
+    fn operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+        assert_eq!(a.intersect(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.subtract(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.split(25), (set(&[(0, 10), (20, 25)]), set(&[(25, 30)])));
+    }
+
+    #[test]
+    fn map() {
+        // the day 5 seed-to-soil map 52 50 48 on the seeds 79 14
+        let seeds = set(&[(79, 93)]);
+        assert_eq!(seeds.map(50..98, 52), (set(&[(81, 95)]), RangeSet::new()));
+        // and part of the range going down
+        let (moved, rest) = set(&[(40, 60)]).map(50..70, 0);
+        assert_eq!((moved, rest), (set(&[(0, 10)]), set(&[(40, 50)])));
+    }
+
+    fn values(set: &RangeSet<u32>) -> BTreeSet<u32> {
+        set.ranges()
+            .iter()
+            .flat_map(|range| range.clone())
+            .collect()
+    }
+
+    fn arbitrary_set() -> impl Strategy<Value = RangeSet<u32>> {
+        prop::collection::vec((0u32..60, 0u32..10), 0..6)
+            .prop_map(|ranges| ranges.into_iter().map(|(s, l)| s..s + l).collect())
+    }
+
+    proptest! {
+        #[test]
+        fn matches_sets(a in arbitrary_set(), b in arbitrary_set(), at in 0u32..70) {
+            let (va, vb) = (values(&a), values(&b));
+            prop_assert_eq!(values(&a.union(&b)), &va | &vb);
+            prop_assert_eq!(values(&a.intersect(&b)), &va & &vb);
+            prop_assert_eq!(values(&a.subtract(&b)), &va - &vb);
+            let (below, above) = a.split(at);
+            prop_assert!(values(&below).iter().all(|v| *v < at));
+            prop_assert_eq!(&values(&below) | &values(&above), va.clone());
+            prop_assert_eq!(a.count() as usize, va.len());
+            // sorted and never touching, so the same values are always the same ranges
+            prop_assert!(a.ranges().windows(2).all(|w| w[0].end < w[1].start));
+        }
+    }
+}
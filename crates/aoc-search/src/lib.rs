@@ -0,0 +1,193 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Path<S, C> {
+    pub cost: C,
+    // from the start state to the goal, inclusive
+    pub states: Vec<S>,
+}
+
+// the cheapest path from start to a state matching is_goal, where neighbours
+// gives the states reachable from a state along with the cost of each step
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+// as dijkstra, but the heuristic estimates the remaining cost to the goal to
+// explore likely states first - it must never overestimate
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // states are stored once and referred to by index from then on, which
+    // keeps the heap small and means S doesn't need to be Ord
+    let mut states = vec![start.clone()];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    // the best known (cost, previous index) for each state
+    let mut best = vec![(C::default(), usize::MAX)];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > best[index].0 {
+            // already reached this state more cheaply
+            continue;
+        }
+        if is_goal(&states[index]) {
+            return Some(Path {
+                cost,
+                states: reconstruct(&states, &best, index),
+            });
+        }
+
+        for (next, step_cost) in neighbours(&states[index]) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+            let next_index = match indices.entry(next) {
+                Entry::Vacant(entry) => {
+                    states.push(entry.key().clone());
+                    best.push((next_cost, index));
+                    *entry.insert(states.len() - 1)
+                }
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= best[next_index].0 {
+                        continue;
+                    }
+                    best[next_index] = (next_cost, index);
+                    next_index
+                }
+            };
+            queue.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+// the cheapest cost to every state reachable from start
+pub fn distances<S, C, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> HashMap<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut states = vec![start.clone()];
+    let mut best = HashMap::from([(start, C::default())]);
+    let mut queue = BinaryHeap::from([Reverse((C::default(), 0))]);
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        if cost > best[&states[index]] {
+            continue;
+        }
+        for (next, step_cost) in neighbours(&states[index]) {
+            let next_cost = cost + step_cost;
+            if best.get(&next).is_some_and(|known| *known <= next_cost) {
+                continue;
+            }
+            best.insert(next.clone(), next_cost);
+            states.push(next);
+            queue.push(Reverse((next_cost, states.len() - 1)));
+        }
+    }
+
+    best
+}
+
+fn reconstruct<S: Clone, C>(states: &[S], best: &[(C, usize)], goal: usize) -> Vec<S> {
+    let mut path = vec![];
+    let mut index = goal;
+    while index != usize::MAX {
+        path.push(states[index].clone());
+        index = best[index].1;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -1-> b -1-> c -1-> d, with a costly shortcut a -5-> d
+    fn graph(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn cheapest_path() {
+        let path = dijkstra('a', graph, |node| *node == 'd').unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states, vec!['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    fn start_is_goal() {
+        let path = dijkstra('a', graph, |node| *node == 'a').unwrap();
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.states, vec!['a']);
+    }
+
+    #[test]
+    fn unreachable() {
+        assert_eq!(dijkstra('b', graph, |node| *node == 'a'), None);
+    }
+
+    #[test]
+    fn astar_on_a_grid() {
+        // walk an open 10x10 grid from the top left to the bottom right
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y))
+                .map(|point| (point, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (9 - x) + (9 - y);
+        let path = astar((0, 0), neighbours, heuristic, |point| *point == (9, 9)).unwrap();
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.states.len(), 19);
+        assert!(path
+            .states
+            .windows(2)
+            .all(|pair| (pair[0].0 - pair[1].0).abs() + (pair[0].1 - pair[1].1).abs() == 1));
+        assert_eq!(
+            path.cost,
+            dijkstra((0, 0), neighbours, |point| *point == (9, 9))
+                .unwrap()
+                .cost
+        );
+    }
+
+    #[test]
+    fn all_distances() {
+        let distances = distances('a', graph);
+        assert_eq!(
+            distances,
+            HashMap::from([('a', 0), ('b', 1), ('c', 2), ('d', 3)])
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Solution;
+
+// for a day's build.rs - writes a test for each examples/*.txt file, which
+// the day pulls in with `examples!()`
+pub fn generate() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("run from a build script");
+    let out_dir = env::var("OUT_DIR").expect("run from a build script");
+    let dir = Path::new(&manifest_dir).join("examples");
+    println!("cargo:rerun-if-changed={}", dir.display());
+    fs::write(Path::new(&out_dir).join("examples.rs"), tests(&files(&dir)))
+        .expect("writable OUT_DIR");
+}
+
+// the example files in name order, none if there's no directory yet
+fn files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+fn tests(files: &[PathBuf]) -> String {
+    files
+        .iter()
+        .map(|path| {
+            format!(
+                "#[test]\n\
+                 fn {}() {{\n    \
+                     aoc_solution::examples::check::<crate::Day>(include_str!({:?}));\n\
+                 }}\n",
+                test_name(path),
+                path.display().to_string(),
+            )
+        })
+        .collect()
+}
+
+// the file name as an identifier, so larger-grid.txt is larger_grid
+fn test_name(path: &Path) -> String {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    let name = stem
+        .chars()
+        .map(|ch| match ch {
+            'a'..='z' | '0'..='9' | '_' => ch,
+            'A'..='Z' => ch.to_ascii_lowercase(),
+            _ => '_',
+        })
+        .collect::<String>();
+    match name.chars().next() {
+        Some('a'..='z' | '_') => name,
+        _ => format!("example_{name}"),
+    }
+}
+
+// an example is the answers it should give, then a blank line, then the input:
+//
+//   part1: 102
+//   part2: 94
+//
+//   2413432311323
+//   ...
+//
+// and either part can be left out if it doesn't apply. Any `name = value`
+// settings above the answers change what the day would use for the real
+// puzzle, as in `seconds = 1000` for a shorter race
+pub fn check<S: Solution>(example: &str) {
+    let (header, input) = example
+        .split_once("\n\n")
+        .expect("expected answers, then a blank line, then the input");
+    assert!(!header.trim().is_empty(), "no expected answers");
+    let mut parsed = S::parse(input).unwrap_or_else(|error| panic!("{}", error.diagnostic(input)));
+    for line in header.lines() {
+        if let Some((name, value)) = line.split_once('=') {
+            let (name, value) = (name.trim(), value.trim());
+            let set = value
+                .parse()
+                .is_ok_and(|value| S::set(&mut parsed, name, value));
+            assert!(set, "can't set {name} to {value}");
+            continue;
+        }
+        let (part, expected) = line
+            .split_once(':')
+            .unwrap_or_else(|| panic!("expected 'partN: answer', found '{line}'"));
+        let number = match part {
+            "part1" => 1,
+            "part2" => 2,
+            _ => panic!("expected part1 or part2, found '{part}'"),
+        };
+        S::check(input, &parsed, number)
+            .unwrap_or_else(|error| panic!("{part}: {}", error.diagnostic(input)));
+        let answer = match number {
+            1 => S::part1(&parsed).to_string(),
+            _ => S::part2(&parsed).to_string(),
+        };
+        assert_eq!(answer, expected.trim(), "{part}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_parse::ParseError;
+    use std::fmt::Display;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Parsed<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+            input
+                .lines()
+                .map(|line| aoc_parse::parse::<u32>(input, line, "a number").map(|_| line))
+                .collect()
+        }
+
+        fn part1(lines: &Self::Parsed<'_>) -> impl Display {
+            lines.len()
+        }
+
+        fn part2(lines: &Self::Parsed<'_>) -> impl Display {
+            lines.join("+")
+        }
+
+        fn set(lines: &mut Self::Parsed<'_>, name: &str, value: u64) -> bool {
+            match name {
+                "first" => {
+                    lines.truncate(value as usize);
+                    true
+                }
+                _ => false,
+            }
+        }
+    }
+
+    #[test]
+    fn both_parts() {
+        check::<Lines>("part1: 3\npart2: 1+2+3\n\n1\n2\n3\n");
+    }
+
+    #[test]
+    fn one_part() {
+        check::<Lines>("part2: 4+5\n\n4\n5\n");
+    }
+
+    #[test]
+    fn settings() {
+        check::<Lines>("first = 2\npart1: 2\npart2: 1+2\n\n1\n2\n3\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "can't set last to 2")]
+    fn unknown_setting() {
+        check::<Lines>("last = 2\npart1: 2\n\n1\n2\n3\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "part1")]
+    fn wrong_answer() {
+        check::<Lines>("part1: 2\n\n1\n2\n3\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a number, found 'x'")]
+    fn invalid_input() {
+        check::<Lines>("part1: 2\n\n1\nx\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected part1 or part2, found 'part3'")]
+    fn unknown_part() {
+        check::<Lines>("part3: 2\n\n1\n");
+    }
+
+    #[test]
+    fn generated() {
+        let files = [
+            PathBuf::from("examples/larger-grid.txt"),
+            PathBuf::from("examples/2.txt"),
+        ];
+        assert_eq!(test_name(&files[0]), "larger_grid");
+        assert_eq!(test_name(&files[1]), "example_2");
+        assert_eq!(
+            tests(&files[..1]),
+            "#[test]\n\
+             fn larger_grid() {\n    \
+                 aoc_solution::examples::check::<crate::Day>(include_str!(\"examples/larger-grid.txt\"));\n\
+             }\n"
+        );
+    }
+}
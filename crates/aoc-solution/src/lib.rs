@@ -0,0 +1,101 @@
+use aoc_parse::ParseError;
+use std::fmt::Display;
+
+pub mod examples;
+
+// one day's puzzle - the input is parsed once, then both parts answer from
+// what it parsed to, which can borrow from the input
+pub trait Solution {
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> impl Display;
+
+    fn part2(parsed: &Self::Parsed<'_>) -> impl Display;
+
+    // whether the input has what a part needs, for inputs that only suit the
+    // other part, like seeds that don't pair up into ranges
+    fn check(_input: &str, _parsed: &Self::Parsed<'_>, _part: u8) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    // changes a number the real puzzle fixes but an example doesn't, like how
+    // long a race lasts, returning false if the day has no such setting
+    fn set(_parsed: &mut Self::Parsed<'_>, _name: &str, _value: u64) -> bool {
+        false
+    }
+}
+
+// a part's answer straight from the input, for the runner and the examples
+pub fn part1<S: Solution>(input: &str) -> Result<String, ParseError> {
+    let parsed = S::parse(input)?;
+    S::check(input, &parsed, 1)?;
+    let answer = S::part1(&parsed).to_string();
+    Ok(answer)
+}
+
+pub fn part2<S: Solution>(input: &str) -> Result<String, ParseError> {
+    let parsed = S::parse(input)?;
+    S::check(input, &parsed, 2)?;
+    let answer = S::part2(&parsed).to_string();
+    Ok(answer)
+}
+
+// the tests build.rs generated from the day's examples directory, one per file
+#[macro_export]
+macro_rules! examples {
+    () => {
+        include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+            input
+                .split(',')
+                .map(|number| aoc_parse::parse::<u32>(input, number, "a number").map(|_| number))
+                .collect()
+        }
+
+        fn part1(numbers: &Self::Parsed<'_>) -> impl Display {
+            numbers.len()
+        }
+
+        fn part2(numbers: &Self::Parsed<'_>) -> impl Display {
+            numbers.join("+")
+        }
+
+        // a sum of one number is just the number
+        fn check(input: &str, numbers: &Self::Parsed<'_>, part: u8) -> Result<(), ParseError> {
+            if part == 2 && numbers.len() < 2 {
+                let message = "expected another number";
+                return Err(ParseError::at(input, aoc_parse::end(input), message));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(part1::<Sum>("1,2,3"), Ok("3".to_string()));
+        assert_eq!(part2::<Sum>("1,2,3"), Ok("1+2+3".to_string()));
+        assert_eq!(
+            part1::<Sum>("1,x").unwrap_err().to_string(),
+            "line 1, column 3: expected a number, found 'x'"
+        );
+        assert_eq!(part1::<Sum>("1"), Ok("1".to_string()));
+        assert_eq!(
+            part2::<Sum>("1").unwrap_err().to_string(),
+            "line 1, column 2: expected another number"
+        );
+    }
+}
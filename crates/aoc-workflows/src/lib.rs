@@ -0,0 +1,486 @@
+use aoc_parse::ParseError;
+use aoc_ranges::HyperRect;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Field {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Field {
+    fn parse(input: &str, name: &str) -> Result<Self, ParseError> {
+        match name {
+            "x" => Ok(Field::X),
+            "m" => Ok(Field::M),
+            "a" => Ok(Field::A),
+            "s" => Ok(Field::S),
+            other => Err(ParseError::at(
+                input,
+                other,
+                format!("unknown field '{other}'"),
+            )),
+        }
+    }
+
+    // where the field's rating is kept in a part, and its axis in a box of parts
+    fn index(&self) -> usize {
+        match self {
+            Field::X => 0,
+            Field::M => 1,
+            Field::A => 2,
+            Field::S => 3,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Comp {
+    Lt,
+    Gt,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Condition {
+    pub field: Field,
+    pub comp: Comp,
+    pub value: u32,
+}
+
+impl Condition {
+    fn test(&self, part: &Part) -> bool {
+        let rating = part.ratings[self.field.index()];
+        match self.comp {
+            Comp::Lt => rating < self.value,
+            Comp::Gt => rating > self.value,
+        }
+    }
+
+    // the parts that pass, and the parts that don't
+    fn split(&self, parts: &Parts) -> (Option<Parts>, Option<Parts>) {
+        let axis = self.field.index();
+        let value = self.value as u64;
+        match self.comp {
+            Comp::Lt => parts.split(axis, value),
+            Comp::Gt => {
+                let (fail, pass) = parts.split(axis, value + 1);
+                (pass, fail)
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Target<'a> {
+    Accept,
+    Reject,
+    Workflow(&'a str),
+}
+
+impl<'a> Target<'a> {
+    fn parse(input: &str, name: &'a str) -> Result<Self, ParseError> {
+        match name {
+            "A" => Ok(Target::Accept),
+            "R" => Ok(Target::Reject),
+            "" => Err(ParseError::at(input, name, "missing target")),
+            name => Ok(Target::Workflow(name)),
+        }
+    }
+}
+
+// where a part goes if it meets the condition - or regardless, without one
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Rule<'a> {
+    pub condition: Option<Condition>,
+    pub target: Target<'a>,
+}
+
+impl<'a> Rule<'a> {
+    fn parse(input: &str, rule: &'a str) -> Result<Self, ParseError> {
+        let Some((condition, target)) = rule.split_once(':') else {
+            return Ok(Rule {
+                condition: None,
+                target: Target::parse(input, rule)?,
+            });
+        };
+        let Some(split) = condition.find(['<', '>']) else {
+            return Err(ParseError::at(input, condition, "expected < or >"));
+        };
+        let field = Field::parse(input, &condition[..split])?;
+        let comp = if condition[split..].starts_with('<') {
+            Comp::Lt
+        } else {
+            Comp::Gt
+        };
+        let value = aoc_parse::parse(input, &condition[split + 1..], "a number")?;
+        Ok(Rule {
+            condition: Some(Condition { field, comp, value }),
+            target: Target::parse(input, target)?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Part {
+    // x, m, a and s
+    pub ratings: [u32; 4],
+}
+
+impl Part {
+    pub fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let fields = line
+            .strip_prefix('{')
+            .and_then(|line| line.strip_suffix('}'))
+            .ok_or_else(|| ParseError::at(input, line, "expected {...}"))?;
+        let mut ratings = [None; 4];
+        for field in fields.split(',') {
+            let (name, value) = field
+                .split_once('=')
+                .ok_or_else(|| ParseError::at(input, field, "expected name=value"))?;
+            let value = aoc_parse::parse(input, value, "a number")?;
+            ratings[Field::parse(input, name)?.index()] = Some(value);
+        }
+        match ratings {
+            [Some(x), Some(m), Some(a), Some(s)] => Ok(Part {
+                ratings: [x, m, a, s],
+            }),
+            _ => Err(ParseError::at(
+                input,
+                line,
+                "expected ratings for x, m, a and s",
+            )),
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.ratings.iter().sum()
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [x, m, a, s] = self.ratings;
+        write!(f, "{{x={x},m={m},a={a},s={s}}}")
+    }
+}
+
+// every combination of x, m, a and s ratings in a range of each
+pub type Parts = HyperRect<u64, 4>;
+
+// the workflows a part went through, and whether it was accepted at the end
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Route<'a> {
+    pub workflows: Vec<&'a str>,
+    pub accepted: bool,
+}
+
+impl Display for Route<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for name in &self.workflows {
+            write!(f, "{name} -> ")?;
+        }
+        write!(f, "{}", if self.accepted { "A" } else { "R" })
+    }
+}
+
+// a set of workflows that's been checked to send every part from in to an
+// accept or reject without looping, so nothing past parsing can fail
+#[derive(Debug)]
+pub struct Workflows<'a> {
+    workflows: HashMap<&'a str, Vec<Rule<'a>>>,
+}
+
+const START: &str = "in";
+
+impl<'a> Workflows<'a> {
+    // the workflows, one per line like px{a<2006:qkq,m>2090:A,rfg}
+    // anything that stops them fitting together is reported at the workflow
+    // or target it's about
+    pub fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let mut workflows = HashMap::new();
+        for line in input.trim().lines() {
+            let (name, rules) = line
+                .strip_suffix('}')
+                .and_then(|line| line.split_once('{'))
+                .ok_or_else(|| ParseError::at(input, line, "expected name{rules}"))?;
+            let rules = rules
+                .split(',')
+                .map(|rule| Rule::parse(input, rule))
+                .collect::<Result<Vec<_>, _>>()?;
+            if workflows.insert(name, rules).is_some() {
+                let message = format!("workflow {name} is defined twice");
+                return Err(ParseError::at(input, name, message));
+            }
+        }
+
+        let workflows = Workflows { workflows };
+        workflows.validate(input)?;
+        Ok(workflows)
+    }
+
+    fn names(&self) -> Vec<&'a str> {
+        let mut names = self.workflows.keys().copied().collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
+    fn next(&self, name: &str) -> impl Iterator<Item = &'a str> + '_ {
+        self.workflows[name]
+            .iter()
+            .filter_map(|rule| match rule.target {
+                Target::Workflow(next) => Some(next),
+                _ => None,
+            })
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ParseError> {
+        if !self.workflows.contains_key(START) {
+            let message = "there's no in workflow to start from";
+            return Err(ParseError::at(input, &input[..0], message));
+        }
+        for name in self.names() {
+            if let Some(target) = self
+                .next(name)
+                .find(|next| !self.workflows.contains_key(next))
+            {
+                let message = format!("workflow {name} sends parts to unknown workflow {target}");
+                return Err(ParseError::at(input, target, message));
+            }
+            if self.workflows[name]
+                .last()
+                .is_none_or(|rule| rule.condition.is_some())
+            {
+                let message =
+                    format!("workflow {name} has nowhere to send parts failing every rule");
+                return Err(ParseError::at(input, name, message));
+            }
+        }
+        let mut done = Vec::new();
+        for name in self.names() {
+            if let Some(cycle) = self.find_cycle(name, &mut vec![], &mut done) {
+                let message = format!("workflows loop: {}", cycle.join(" -> "));
+                return Err(ParseError::at(input, cycle[0], message));
+            }
+        }
+        Ok(())
+    }
+
+    // a depth first search - a workflow already on the path means a loop
+    fn find_cycle(
+        &self,
+        name: &'a str,
+        path: &mut Vec<&'a str>,
+        done: &mut Vec<&'a str>,
+    ) -> Option<Vec<&'a str>> {
+        if let Some(start) = path.iter().position(|n| *n == name) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(name);
+            return Some(cycle);
+        }
+        if done.contains(&name) {
+            return None;
+        }
+        path.push(name);
+        for next in self.next(name) {
+            if let Some(cycle) = self.find_cycle(next, path, done) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        done.push(name);
+        None
+    }
+
+    pub fn explain(&self, part: &Part) -> Route<'a> {
+        let mut workflows = vec![START];
+        loop {
+            let name = workflows[workflows.len() - 1];
+            let rule = self.workflows[name]
+                .iter()
+                .find(|rule| rule.condition.is_none_or(|c| c.test(part)))
+                .expect("validated to end with a fallback");
+            match rule.target {
+                Target::Accept => {
+                    return Route {
+                        workflows,
+                        accepted: true,
+                    }
+                }
+                Target::Reject => {
+                    return Route {
+                        workflows,
+                        accepted: false,
+                    }
+                }
+                Target::Workflow(next) => workflows.push(next),
+            }
+        }
+    }
+
+    pub fn accepts(&self, part: &Part) -> bool {
+        self.explain(part).accepted
+    }
+
+    // how many of the parts would be accepted - each condition splits off the
+    // parts that pass, and the rest carry on down the rules
+    pub fn count_accepted(&self, parts: Parts) -> u64 {
+        self.count_from(START, parts)
+    }
+
+    fn count_from(&self, name: &str, parts: Parts) -> u64 {
+        let mut count = 0;
+        let mut rest = Some(parts);
+        for rule in &self.workflows[name] {
+            let Some(parts) = rest.take() else {
+                break;
+            };
+            let (pass, fail) = match rule.condition {
+                Some(condition) => condition.split(&parts),
+                None => (Some(parts), None),
+            };
+            if let Some(pass) = pass {
+                count += match rule.target {
+                    Target::Accept => pass.volume(),
+                    Target::Reject => 0,
+                    Target::Workflow(next) => self.count_from(next, pass),
+                };
+            }
+            rest = fail;
+        }
+        count
+    }
+}
+
+// the workflows, a blank line, then the parts
+pub fn parse_input(input: &str) -> Result<(Workflows<'_>, Vec<Part>), ParseError> {
+    let (workflows, parts) = input.trim().split_once("\n\n").unwrap_or((input, ""));
+    let parts = parts
+        .lines()
+        .map(|line| Part::parse(input, line))
+        .collect::<Result<_, _>>()?;
+    let workflows = Workflows::parse(workflows).map_err(|error| error.within(input, workflows))?;
+    Ok((workflows, parts))
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = r"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explain() {
+        let (workflows, parts) = parse_input(EXAMPLE).unwrap();
+        let routes = parts
+            .iter()
+            .map(|part| format!("{part}: {}", workflows.explain(part)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            routes,
+            [
+                "{x=787,m=2655,a=1222,s=2876}: in -> qqz -> qs -> lnx -> A",
+                "{x=1679,m=44,a=2067,s=496}: in -> px -> rfg -> gd -> R",
+                "{x=2036,m=264,a=79,s=2244}: in -> qqz -> hdj -> pv -> A",
+                "{x=2461,m=1339,a=466,s=291}: in -> px -> qkq -> crn -> R",
+                "{x=2127,m=1623,a=2188,s=1013}: in -> px -> rfg -> A",
+            ]
+        );
+    }
+
+    #[test]
+    fn count_within_bounds() {
+        let (workflows, _) = parse_input(EXAMPLE).unwrap();
+        // just the one part, accepted or not
+        let part = Parts::new([787..788, 2655..2656, 1222..1223, 2876..2877]);
+        assert_eq!(workflows.count_accepted(part), 1);
+        let part = Parts::new([1679..1680, 44..45, 2067..2068, 496..497]);
+        assert_eq!(workflows.count_accepted(part), 0);
+        // in sends everything with s < 1351 to px, which accepts all of it
+        // with a < 2006 and x < 1416
+        let parts = Parts::new([1..1416, 1..4001, 1..2006, 1..1351]);
+        assert_eq!(workflows.count_accepted(parts.clone()), parts.volume());
+    }
+
+    #[test]
+    fn errors() {
+        let error = |input| Workflows::parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("in{x<10:A,R}\nin{R}"),
+            "line 2, column 1: workflow in is defined twice"
+        );
+        assert_eq!(
+            error("in{x<10:a,b}\na{R}"),
+            "line 1, column 11: workflow in sends parts to unknown workflow b"
+        );
+        assert_eq!(
+            error("start{A}"),
+            "line 1, column 1: there's no in workflow to start from"
+        );
+        assert_eq!(
+            error("in{x<10:A}"),
+            "line 1, column 1: workflow in has nowhere to send parts failing every rule"
+        );
+        assert_eq!(
+            error("in{x<10:a,R}\na{m>5:b,R}\nb{a}"),
+            "line 2, column 1: workflows loop: a -> b -> a"
+        );
+        assert_eq!(
+            error("in{A}\nb{q<1:A,R}"),
+            "line 2, column 3: unknown field 'q'"
+        );
+        assert_eq!(error("in{x=1:A,R}"), "line 1, column 4: expected < or >");
+        assert_eq!(
+            parse_input("in{A}\n\n{x=1,m=2,a=3}")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 1: expected ratings for x, m, a and s"
+        );
+        assert_eq!(
+            parse_input("\nin{A}\nb{x<y:A,R}\n\n{x=1,m=2,a=3,s=4}")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 5: expected a number, found 'y'"
+        );
+    }
+
+    #[test]
+    fn placed() {
+        // within the whole input, as the runner shows them
+        let input = "in{s<5:px,R}\npx{a>1:in,A}\n\n{x=1,m=2,a=3,s=4}";
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: workflows loop: in -> px -> in"
+        );
+        let input = "in{s<5:px,R}\npx{a>1:qq,A}\n\n{x=1,m=2,a=3,s=4}";
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(
+            error.diagnostic(input),
+            "error: workflow px sends parts to unknown workflow qq
+ --> line 2, column 8
+  |
+2 | px{a>1:qq,A}
+  |        ^^"
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// stop repeating a slow day once it has used this much time
+pub const BUDGET: Duration = Duration::from_secs(10);
+// smaller changes than this are noise, however big they are in percent
+const NOISE: Duration = Duration::from_millis(1);
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Timing {
+    pub year: u16,
+    pub day: u8,
+    // 0 for parsing the input
+    pub part: u8,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Timing {
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Run {
+    // seconds since the unix epoch
+    pub timestamp: u64,
+    pub timings: Vec<Timing>,
+}
+
+#[derive(Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Change {
+    New,
+    Same,
+    Faster(Duration),
+    Slower(Duration),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::New => write!(f, "new"),
+            Change::Same => write!(f, "no change"),
+            Change::Faster(before) => write!(f, "faster, was {before:.2?}"),
+            Change::Slower(before) => write!(f, "SLOWER, was {before:.2?}"),
+        }
+    }
+}
+
+impl History {
+    // a missing file is an empty history
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|err| format!("invalid history {}: {err}", path.display())),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(format!("couldn't read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).expect("history serializes");
+        fs::write(path, json + "\n")
+            .map_err(|err| format!("couldn't write {}: {err}", path.display()))
+    }
+
+    // the most recent timing of the same part, from any earlier run
+    pub fn previous(&self, year: u16, day: u8, part: u8) -> Option<&Timing> {
+        self.runs.iter().rev().find_map(|run| {
+            run.timings
+                .iter()
+                .find(|timing| (timing.year, timing.day, timing.part) == (year, day, part))
+        })
+    }
+
+    // compares medians, as the mean is skewed by the odd slow run
+    pub fn compare(&self, timing: &Timing, threshold_percent: u32) -> Change {
+        let Some(previous) = self.previous(timing.year, timing.day, timing.part) else {
+            return Change::New;
+        };
+        let (now, before) = (timing.median(), previous.median());
+        let margin = (before * threshold_percent / 100).max(NOISE);
+        if now > before + margin {
+            Change::Slower(before)
+        } else if now + margin < before {
+            Change::Faster(before)
+        } else {
+            Change::Same
+        }
+    }
+
+    pub fn record(&mut self, timings: Vec<Timing>) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        self.runs.push(Run { timestamp, timings });
+    }
+}
+
+// calls f up to runs times, stopping early once the budget is used up, for
+// f's own timings of each run
+pub fn sample<T>(runs: usize, mut f: impl FnMut() -> T) -> Vec<T> {
+    let mut samples = vec![];
+    let mut total = Duration::ZERO;
+    while samples.len() < runs.max(1) && (samples.is_empty() || total < BUDGET) {
+        let now = Instant::now();
+        samples.push(f());
+        total += now.elapsed();
+    }
+    samples
+}
+
+pub fn summarize(year: u16, day: u8, part: u8, times: &[Duration]) -> Timing {
+    let mut nanos = times
+        .iter()
+        .map(|time| time.as_nanos() as u64)
+        .collect::<Vec<_>>();
+    nanos.sort_unstable();
+    let middle = nanos.len() / 2;
+    let median_ns = if nanos.len() % 2 == 0 {
+        (nanos[middle - 1] + nanos[middle]) / 2
+    } else {
+        nanos[middle]
+    };
+    Timing {
+        year,
+        day,
+        part,
+        runs: nanos.len(),
+        min_ns: nanos[0],
+        median_ns,
+        mean_ns: nanos.iter().sum::<u64>() / nanos.len() as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(day: u8, median_ms: u64) -> Timing {
+        summarize(2023, day, 1, &[Duration::from_millis(median_ms)])
+    }
+
+    #[test]
+    fn stats() {
+        let times = [5, 1, 3, 100].map(Duration::from_millis);
+        let timing = summarize(2015, 4, 2, &times);
+        assert_eq!(timing.runs, 4);
+        assert_eq!(timing.min_ns, 1_000_000);
+        assert_eq!(timing.median_ns, 4_000_000);
+        assert_eq!(timing.mean_ns, 27_250_000);
+        assert_eq!(summarize(2015, 4, 2, &times[..3]).median_ns, 3_000_000);
+    }
+
+    #[test]
+    fn regressions() {
+        let mut history = History::default();
+        assert_eq!(history.compare(&timing(5, 100), 10), Change::New);
+
+        history.record(vec![timing(5, 100), timing(12, 100)]);
+        history.record(vec![timing(5, 200)]);
+        // compared with the latest run that has the same part
+        assert_eq!(
+            history.compare(&timing(5, 100), 10),
+            Change::Faster(Duration::from_millis(200))
+        );
+        assert_eq!(
+            history.compare(&timing(12, 150), 10),
+            Change::Slower(Duration::from_millis(100))
+        );
+        assert_eq!(history.compare(&timing(12, 105), 10), Change::Same);
+        assert_eq!(history.compare(&timing(14, 1), 10), Change::New);
+    }
+
+    #[test]
+    fn tiny_changes_are_noise() {
+        let mut history = History::default();
+        history.record(vec![summarize(2023, 1, 1, &[Duration::from_micros(100)])]);
+        let timing = summarize(2023, 1, 1, &[Duration::from_micros(300)]);
+        assert_eq!(history.compare(&timing, 10), Change::Same);
+    }
+
+    #[test]
+    fn history_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert_eq!(History::load(&path).unwrap(), History::default());
+
+        let mut history = History::default();
+        history.record(vec![timing(5, 100)]);
+        history.save(&path).unwrap();
+        assert_eq!(History::load(&path).unwrap(), history);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn runs() {
+        let mut calls = 0;
+        let samples = sample(3, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(samples, [1, 2, 3]);
+        // always at least once
+        assert_eq!(sample(0, || ()).len(), 1);
+    }
+}
@@ -1,3 +1,623 @@
+use aoc_answers::{Answers, Status};
+use aoc_input::Loader;
+use bench::{Change, History};
+use rayon::prelude::*;
+use solutions::{Failure, Puzzle, Solved};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::slice::Iter;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use std::{env, process, thread};
+
+mod bench;
+mod scaffold;
+mod solutions;
+
+const USAGE: &str = "usage:
+    aoc run <year> (<day> | --all) [--part <1|2>] [--input <file>] [--cache-dir <dir>]
+        [--jobs <n>]
+    aoc bench <year> (<day> | --all) [--part <1|2>] [--runs <n>] [--threshold <percent>]
+        [--history <file>] [--cache-dir <dir>]
+    aoc new <year> <day> [--example <puzzle.html>]";
+
+#[derive(Debug, PartialEq)]
+enum Days {
+    One(u8),
+    All,
+}
+
+#[derive(Debug, PartialEq)]
+struct RunArgs {
+    year: u16,
+    days: Days,
+    part: Option<u8>,
+    // read this file instead of the cached input
+    input: Option<PathBuf>,
+    cache_dir: Option<PathBuf>,
+    // how many days to solve at once, defaulting to one per core
+    jobs: Option<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+struct BenchArgs {
+    run: RunArgs,
+    runs: usize,
+    // how much slower (in percent) counts as a regression
+    threshold: u32,
+    history: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+struct NewArgs {
+    year: u16,
+    day: u8,
+    // a saved puzzle page to take the example from
+    example: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    New(NewArgs),
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    match args.next().map(String::as_str) {
+        Some("run") => parse_run_args(args, |_, _| Ok(false)).map(Command::Run),
+        Some("bench") => parse_bench_args(args).map(Command::Bench),
+        Some("new") => parse_new_args(args).map(Command::New),
+        Some(other) => Err(format!("unknown command {other}")),
+        None => Err("missing command".to_string()),
+    }
+}
+
+fn parse_year(args: &mut Iter<String>) -> Result<u16, String> {
+    args.next()
+        .ok_or("missing year")?
+        .parse()
+        .map_err(|_| "year must be a number".to_string())
+}
+
+fn parse_new_args(mut args: Iter<String>) -> Result<NewArgs, String> {
+    let year = parse_year(&mut args)?;
+    let day = args.next().ok_or("missing day")?;
+    let day = match day.parse() {
+        Ok(day @ 1..=25) => day,
+        _ => return Err(format!("invalid day {day}")),
+    };
+    let mut example = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--example" => example = Some(args.next().ok_or("missing value for --example")?.into()),
+            other => return Err(format!("unexpected argument {other}")),
+        }
+    }
+    Ok(NewArgs { year, day, example })
+}
+
+fn parse_bench_args(args: Iter<String>) -> Result<BenchArgs, String> {
+    let mut runs = 5;
+    let mut threshold = 10;
+    let mut history = None;
+    let run = parse_run_args(args, |arg, args| {
+        match arg {
+            "--runs" => {
+                let value = args.next().ok_or("missing value for --runs")?;
+                runs = match value.parse() {
+                    Ok(runs) if runs > 0 => runs,
+                    _ => return Err(format!("runs must be a positive number, got {value}")),
+                };
+            }
+            "--threshold" => {
+                let value = args.next().ok_or("missing value for --threshold")?;
+                threshold = value
+                    .parse()
+                    .map_err(|_| format!("threshold must be a percentage, got {value}"))?;
+            }
+            "--history" => history = Some(args.next().ok_or("missing value for --history")?.into()),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    if run.input.is_some() {
+        return Err("--input can't be used with bench".to_string());
+    }
+    // timings are only comparable with nothing else running
+    if run.jobs.is_some() {
+        return Err("--jobs can't be used with bench".to_string());
+    }
+    Ok(BenchArgs {
+        run,
+        runs,
+        threshold,
+        history,
+    })
+}
+
+// extra handles any arguments specific to the command, returning false for
+// ones it doesn't know
+fn parse_run_args(
+    mut args: Iter<String>,
+    mut extra: impl FnMut(&str, &mut Iter<String>) -> Result<bool, String>,
+) -> Result<RunArgs, String> {
+    let year = parse_year(&mut args)?;
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = None;
+    let mut cache_dir = None;
+    let mut jobs = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" => {
+                let value = args.next().ok_or("missing value for --part")?;
+                match value.as_str() {
+                    "1" => part = Some(1),
+                    "2" => part = Some(2),
+                    _ => return Err(format!("part must be 1 or 2, got {value}")),
+                }
+            }
+            "--input" => input = Some(args.next().ok_or("missing value for --input")?.into()),
+            "--cache-dir" => {
+                cache_dir = Some(args.next().ok_or("missing value for --cache-dir")?.into())
+            }
+            "--jobs" => {
+                let value = args.next().ok_or("missing value for --jobs")?;
+                jobs = match value.parse() {
+                    Ok(jobs) if jobs > 0 => Some(jobs),
+                    _ => return Err(format!("jobs must be a positive number, got {value}")),
+                };
+            }
+            other if day.is_none() && !other.starts_with('-') => {
+                day = Some(other.parse().map_err(|_| format!("invalid day {other}"))?)
+            }
+            other if extra(other, &mut args)? => {}
+            other => return Err(format!("unexpected argument {other}")),
+        }
+    }
+
+    let days = match (day, all) {
+        (Some(day), false) => Days::One(day),
+        (None, true) => Days::All,
+        (Some(_), true) => return Err("use either a day or --all, not both".to_string()),
+        (None, false) => return Err("missing day (or --all)".to_string()),
+    };
+    if input.is_some() && days == Days::All {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    Ok(RunArgs {
+        year,
+        days,
+        part,
+        input,
+        cache_dir,
+        jobs,
+    })
+}
+
+// each day with the parts to run of it
+fn select(
+    year: u16,
+    days: &Days,
+    part: Option<u8>,
+) -> Result<Vec<(&'static Puzzle, Vec<u8>)>, String> {
+    let puzzles = solutions::for_year(year).ok_or(format!("no solutions for {year}"))?;
+    let selected = puzzles
+        .iter()
+        .filter(|puzzle| match days {
+            Days::One(day) => puzzle.day == *day,
+            Days::All => true,
+        })
+        .map(|puzzle| {
+            let parts = (1..=puzzle.parts)
+                .filter(|number| part.is_none_or(|part| *number == part))
+                .collect::<Vec<_>>();
+            (puzzle, parts)
+        })
+        .filter(|(_, parts)| !parts.is_empty())
+        .collect::<Vec<_>>();
+    if selected.is_empty() {
+        return Err(format!("no matching solutions for {year}"));
+    }
+    Ok(selected)
+}
+
+fn loader(cache_dir: Option<PathBuf>) -> Loader {
+    match cache_dir {
+        Some(cache_dir) => Loader::from_env().with_cache_dir(cache_dir),
+        None => Loader::from_env(),
+    }
+}
+
+fn run(
+    RunArgs {
+        year,
+        days,
+        part,
+        input,
+        cache_dir,
+        jobs,
+    }: RunArgs,
+) -> Result<(), String> {
+    let selected = select(year, &days, part)?;
+    let loader = loader(cache_dir);
+    // the known answers only apply to the cached inputs
+    let answers = match &input {
+        Some(_) => None,
+        None => Some(
+            Answers::load(aoc_answers::path(loader.cache_dir(), year))
+                .map_err(|err| err.to_string())?,
+        ),
+    };
+    // a missing input only fails its own day
+    let inputs = selected
+        .iter()
+        .map(|(puzzle, _)| {
+            match &input {
+                Some(path) => aoc_input::load_file(path),
+                None => loader.load(year, puzzle.day),
+            }
+            .map_err(|err| err.to_string())
+        })
+        .collect::<Vec<_>>();
+
+    let mut statuses = vec![];
+    let mut failed = 0;
+    let mut sum = Duration::ZERO;
+    let mut report = |puzzle: &Puzzle, input: &str, result: Result<Solved, Failure>| {
+        let solved = match result {
+            Ok(solved) => solved,
+            Err(failure) => {
+                println!("{year} day {:02}: {failure}", puzzle.day);
+                if let Some(diagnostic) = failure.diagnostic(input) {
+                    eprintln!("{diagnostic}");
+                }
+                failed += 1;
+                return;
+            }
+        };
+        println!(
+            "{year} day {:02} parsed (time: {:.2?})",
+            puzzle.day, solved.parse
+        );
+        sum += solved.parse;
+        for (part, answer, elapsed) in solved.answers {
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(failure) => {
+                    println!("{year} day {:02} part {part}: {failure}", puzzle.day);
+                    if let Some(diagnostic) = failure.diagnostic(input) {
+                        eprintln!("{diagnostic}");
+                    }
+                    failed += 1;
+                    continue;
+                }
+            };
+            let status = answers
+                .as_ref()
+                .map(|answers| answers.check(puzzle.day, part, &answer));
+            println!(
+                "{year} day {:02} part {part}: {answer} (time: {elapsed:.2?}){}",
+                puzzle.day,
+                status
+                    .as_ref()
+                    .map_or(String::new(), |status| format!(" [{status}]"))
+            );
+            sum += elapsed;
+            statuses.extend(status);
+        }
+    };
+
+    // the days finish in any order, but each is printed once it and every day
+    // before it are done
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.unwrap_or(0))
+        .build()
+        .map_err(|err| err.to_string())?;
+    let now = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            pool.install(|| {
+                selected
+                    .par_iter()
+                    .zip(&inputs)
+                    .enumerate()
+                    .map(|(index, ((puzzle, parts), input))| match input {
+                        Ok(input) => (index, (puzzle.solve)(input, parts)),
+                        Err(error) => (index, Err(Failure::Missing(error.clone()))),
+                    })
+                    .for_each_with(sender, |sender, solved| {
+                        sender.send(solved).expect("the printer outlives the days");
+                    });
+            })
+        });
+
+        let mut done = BTreeMap::new();
+        let mut next = 0;
+        for (index, result) in receiver {
+            done.insert(index, result);
+            while let Some(result) = done.remove(&next) {
+                let input = inputs[next].as_deref().unwrap_or_default();
+                report(selected[next].0, input, result);
+                next += 1;
+            }
+        }
+    });
+    let total = now.elapsed();
+
+    if statuses.len() > 1 {
+        let count = |f: fn(&Status) -> bool| statuses.iter().filter(|status| f(status)).count();
+        println!(
+            "{} passed, {} failed, {} known wrong, {} unknown",
+            count(|status| *status == Status::Pass),
+            count(|status| matches!(status, Status::Fail { .. })),
+            count(|status| *status == Status::KnownWrong),
+            count(|status| *status == Status::Unknown),
+        );
+    }
+    if selected.len() > 1 {
+        println!(
+            "total time: {total:.2?} on {} thread(s), against {sum:.2?} summed over the days",
+            pool.current_num_threads()
+        );
+    }
+    if failed > 0 {
+        return Err(format!("{failed} failed day(s) or part(s) for {year}"));
+    }
+    let wrong = statuses.iter().filter(|status| !status.is_ok()).count();
+    if wrong > 0 {
+        return Err(format!("{wrong} wrong answer(s) for {year}"));
+    }
+    Ok(())
+}
+
+fn bench(
+    BenchArgs {
+        run:
+            RunArgs {
+                year,
+                days,
+                part,
+                cache_dir,
+                ..
+            },
+        runs,
+        threshold,
+        history,
+    }: BenchArgs,
+) -> Result<(), String> {
+    let selected = select(year, &days, part)?;
+    let loader = loader(cache_dir);
+    let history_path =
+        history.unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("bench-history.json"));
+    let mut history = History::load(&history_path)?;
+
+    let mut timings = vec![];
+    let mut slower = 0;
+    for (puzzle, parts) in selected {
+        let input = loader
+            .load(year, puzzle.day)
+            .map_err(|err| err.to_string())?;
+        // no point timing how quickly it gives up
+        let describe = |failure: Failure| {
+            let summary = format!("{year} day {:02}: {failure}", puzzle.day);
+            failure.diagnostic(&input).unwrap_or(summary)
+        };
+        let solved = (puzzle.solve)(&input, &parts).map_err(describe)?;
+        for (_, answer, _) in solved.answers {
+            answer.map_err(describe)?;
+        }
+        let samples = bench::sample(runs, || {
+            (puzzle.solve)(&input, &parts).expect("input that parsed before")
+        });
+
+        // parsing is timed as part 0
+        let parse = samples
+            .iter()
+            .map(|solved| solved.parse)
+            .collect::<Vec<_>>();
+        let mut day_timings = vec![bench::summarize(year, puzzle.day, 0, &parse)];
+        for (index, part) in parts.iter().enumerate() {
+            let times = samples
+                .iter()
+                .map(|solved| solved.answers[index].2)
+                .collect::<Vec<_>>();
+            day_timings.push(bench::summarize(year, puzzle.day, *part, &times));
+        }
+        for timing in day_timings {
+            let change = history.compare(&timing, threshold);
+            let name = match timing.part {
+                0 => "parsing".to_string(),
+                part => format!("part {part}"),
+            };
+            println!(
+                "{year} day {:02} {name}: min {:.2?}, median {:.2?}, mean {:.2?} ({} runs) [{change}]",
+                puzzle.day,
+                Duration::from_nanos(timing.min_ns),
+                timing.median(),
+                Duration::from_nanos(timing.mean_ns),
+                timing.runs,
+            );
+            if matches!(change, Change::Slower(_)) {
+                slower += 1;
+            }
+            timings.push(timing);
+        }
+    }
+
+    history.record(timings);
+    history.save(&history_path)?;
+    if slower > 0 {
+        println!("{slower} regression(s) since the previous run");
+    }
+    Ok(())
+}
+
+fn new(NewArgs { year, day, example }: NewArgs) -> Result<(), String> {
+    let example = match example {
+        Some(path) => {
+            let html = aoc_input::load_file(&path).map_err(|err| err.to_string())?;
+            let example = scaffold::extract_example(&html)
+                .ok_or(format!("no <pre><code> example in {}", path.display()))?;
+            Some(example)
+        }
+        None => None,
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for change in scaffold::new_day(root, year, day, example.as_deref())? {
+        println!("{change}");
+    }
+    println!(
+        "input: {}",
+        Loader::from_env()
+            .with_cache_dir(root)
+            .path(year, day)
+            .display()
+    );
+    Ok(())
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let command = parse_args(&args).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        eprintln!("{USAGE}");
+        process::exit(1);
+    });
+    let result = match command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::New(args) => new(args),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn run_one_part() {
+        assert_eq!(
+            parse_args(&args("run 2023 17 --part 2")),
+            Ok(Command::Run(RunArgs {
+                year: 2023,
+                days: Days::One(17),
+                part: Some(2),
+                input: None,
+                cache_dir: None,
+                jobs: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn run_all() {
+        assert_eq!(
+            parse_args(&args("run 2015 --all")),
+            Ok(Command::Run(RunArgs {
+                year: 2015,
+                days: Days::All,
+                part: None,
+                input: None,
+                cache_dir: None,
+                jobs: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn run_in_parallel() {
+        assert_eq!(
+            parse_args(&args("run 2023 --all --jobs 4")),
+            Ok(Command::Run(RunArgs {
+                year: 2023,
+                days: Days::All,
+                part: None,
+                input: None,
+                cache_dir: None,
+                jobs: Some(4),
+            }))
+        );
+        assert!(parse_args(&args("run 2023 --all --jobs 0")).is_err());
+        assert!(parse_args(&args("run 2023 --all --jobs")).is_err());
+    }
+
+    #[test]
+    fn run_with_inputs() {
+        assert_eq!(
+            parse_args(&args("run 2023 5 --input example.txt --cache-dir /tmp/aoc")),
+            Ok(Command::Run(RunArgs {
+                year: 2023,
+                days: Days::One(5),
+                part: None,
+                input: Some("example.txt".into()),
+                cache_dir: Some("/tmp/aoc".into()),
+                jobs: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(
+            parse_args(&args("bench 2023 --all --runs 10 --threshold 25")),
+            Ok(Command::Bench(BenchArgs {
+                run: RunArgs {
+                    year: 2023,
+                    days: Days::All,
+                    part: None,
+                    input: None,
+                    cache_dir: None,
+                    jobs: None,
+                },
+                runs: 10,
+                threshold: 25,
+                history: None,
+            }))
+        );
+        assert!(parse_args(&args("bench 2023 5 --runs 0")).is_err());
+        assert!(parse_args(&args("bench 2023 5 --input example.txt")).is_err());
+        assert!(parse_args(&args("run 2023 5 --runs 3")).is_err());
+        assert!(parse_args(&args("bench 2023 --all --jobs 4")).is_err());
+    }
+
+    #[test]
+    fn new_day() {
+        assert_eq!(
+            parse_args(&args("new 2024 5 --example day5.html")),
+            Ok(Command::New(NewArgs {
+                year: 2024,
+                day: 5,
+                example: Some("day5.html".into()),
+            }))
+        );
+    }
+
+    #[test]
+    fn invalid_args() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("walk 2015 1")).is_err());
+        assert!(parse_args(&args("run 2015")).is_err());
+        assert!(parse_args(&args("run 2015 1 --all")).is_err());
+        assert!(parse_args(&args("run 2015 1 --part 3")).is_err());
+        assert!(parse_args(&args("run 2015 --all --input example.txt")).is_err());
+        assert!(parse_args(&args("run 2015 1 --input")).is_err());
+        assert!(parse_args(&args("new 2024")).is_err());
+        assert!(parse_args(&args("new 2024 26")).is_err());
+        assert!(parse_args(&args("new 2024 5 --part 1")).is_err());
+    }
 }
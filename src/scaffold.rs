@@ -0,0 +1,314 @@
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../template.rs");
+const BUILD: &str =
+    "// one test per file in examples/\nfn main() {\n    aoc_solution::examples::generate();\n}\n";
+
+#[derive(Debug, PartialEq)]
+pub enum Change {
+    Created(PathBuf),
+    Updated(PathBuf),
+    // already there, so left alone
+    Kept(PathBuf),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Created(path) => write!(f, "created {}", path.display()),
+            Change::Updated(path) => write!(f, "updated {}", path.display()),
+            Change::Kept(path) => write!(f, "kept    {}", path.display()),
+        }
+    }
+}
+
+// creates {year}/day-{dd} under root from the template and wires it into the
+// runner, never overwriting a file that already exists
+pub fn new_day(
+    root: &Path,
+    year: u16,
+    day: u8,
+    example: Option<&str>,
+) -> Result<Vec<Change>, String> {
+    let name = format!("y{year}-day-{day:02}");
+    let dir = PathBuf::from(year.to_string()).join(format!("day-{day:02}"));
+    let mut changes = vec![];
+
+    let manifest = format!(
+        "[package]\n\
+         name = \"{name}\"\n\
+         version = \"0.1.0\"\n\
+         edition = \"2021\"\n\
+         \n\
+         [dependencies]\n\
+         aoc-parse.workspace = true\n\
+         aoc-solution.workspace = true\n\
+         \n\
+         [build-dependencies]\n\
+         aoc-solution.workspace = true\n"
+    );
+    changes.push(create(root, &dir.join("Cargo.toml"), &manifest)?);
+    changes.push(create(root, &dir.join("build.rs"), BUILD)?);
+    changes.push(create(root, &dir.join("src/lib.rs"), TEMPLATE)?);
+
+    // the answers aren't known yet, so the example test fails until they're filled in
+    let example = format!("part1: ?\npart2: ?\n\n{}\n", example.unwrap_or_default());
+    changes.push(create(root, &dir.join("examples/example.txt"), &example)?);
+
+    // the workspace picks up 20*/day-* itself, but the runner needs to know
+    let dependency = format!(
+        "{name} = {{ path = \"{}\" }}",
+        dir.display().to_string().replace('\\', "/")
+    );
+    changes.push(update(root, Path::new("Cargo.toml"), |manifest| {
+        if manifest
+            .lines()
+            .any(|line| line.starts_with(&format!("{name} ")))
+        {
+            return None;
+        }
+        insert_sorted(manifest, &dependency, |line| {
+            line.starts_with('y') && line.contains("= { path = ")
+        })
+    })?);
+
+    changes.push(update(root, Path::new("src/solutions.rs"), |solutions| {
+        let module = format!("mod y{year};");
+        let arm = format!("        {year} => Some(y{year}::PUZZLES),");
+        let mut solutions = solutions.to_string();
+        if !solutions.lines().any(|line| line == module) {
+            solutions = insert_sorted(&solutions, &module, |line| line.starts_with("mod y"))?;
+        }
+        if !solutions.lines().any(|line| line == arm) {
+            solutions = insert_sorted(&solutions, &arm, |line| {
+                line.trim_start().starts_with("20") && line.contains(" => Some(")
+            })?;
+        }
+        Some(solutions)
+    })?);
+
+    let year_solutions = PathBuf::from(format!("src/solutions/y{year}.rs"));
+    if !root.join(&year_solutions).exists() {
+        create(
+            root,
+            &year_solutions,
+            "use super::{solve, Puzzle};\n\npub const PUZZLES: &[Puzzle] = &[\n];\n",
+        )?;
+    }
+    changes.push(update(root, &year_solutions, |solutions| {
+        add_puzzle(solutions, &name.replace('-', "_"), day)
+    })?);
+
+    Ok(changes)
+}
+
+// the first <pre><code> block, which is the example in nearly every puzzle
+pub fn extract_example(html: &str) -> Option<String> {
+    let start = html.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + html[start..].find("</code></pre>")?;
+
+    // drop the <em> highlighting and any other tags
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in html[start..end].chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            ch if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    let text = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    Some(text.trim_end().to_string())
+}
+
+fn create(root: &Path, path: &Path, contents: &str) -> Result<Change, String> {
+    let full_path = root.join(path);
+    if full_path.exists() {
+        return Ok(Change::Kept(path.to_path_buf()));
+    }
+    let error = |err: std::io::Error| format!("couldn't write {}: {err}", full_path.display());
+    fs::create_dir_all(full_path.parent().expect("file has a parent")).map_err(error)?;
+    fs::write(&full_path, contents).map_err(error)?;
+    Ok(Change::Created(path.to_path_buf()))
+}
+
+// f returns the new contents, or None if there's nothing to change
+fn update(
+    root: &Path,
+    path: &Path,
+    f: impl FnOnce(&str) -> Option<String>,
+) -> Result<Change, String> {
+    let full_path = root.join(path);
+    let contents = fs::read_to_string(&full_path)
+        .map_err(|err| format!("couldn't read {}: {err}", full_path.display()))?;
+    match f(&contents) {
+        Some(updated) if updated != contents => {
+            fs::write(&full_path, updated)
+                .map_err(|err| format!("couldn't write {}: {err}", full_path.display()))?;
+            Ok(Change::Updated(path.to_path_buf()))
+        }
+        _ => Ok(Change::Kept(path.to_path_buf())),
+    }
+}
+
+// adds line among the (sorted) lines matching is_peer, None if there are none
+fn insert_sorted(text: &str, line: &str, is_peer: impl Fn(&str) -> bool) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let peers = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, existing)| is_peer(existing))
+        .collect::<Vec<_>>();
+    let index = match peers.iter().find(|(_, existing)| **existing > line) {
+        Some((index, _)) => *index,
+        None => peers.last()?.0 + 1,
+    };
+    lines.insert(index, line);
+    Some(lines.join("\n") + "\n")
+}
+
+// the runner's entry for the day, kept in day order
+fn add_puzzle(solutions: &str, crate_name: &str, day: u8) -> Option<String> {
+    if solutions.contains(&format!("{crate_name}::")) {
+        return None;
+    }
+    let entry = format!(
+        "    Puzzle {{\n        \
+             day: {day},\n        \
+             parts: 2,\n        \
+             solve: solve::<{crate_name}::Day>,\n    \
+         }},\n"
+    );
+
+    let later_day = solutions
+        .match_indices("    Puzzle {\n")
+        .find(|(index, _)| {
+            solutions[*index..]
+                .lines()
+                .nth(1)
+                .and_then(|line| line.trim().strip_prefix("day: "))
+                .and_then(|n| n.trim_end_matches(',').parse::<u8>().ok())
+                .is_some_and(|n| n > day)
+        });
+    let index = match later_day {
+        Some((index, _)) => index,
+        None => solutions.rfind("];")?,
+    };
+    Some(format!(
+        "{}{entry}{}",
+        &solutions[..index],
+        &solutions[index..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT_MANIFEST: &str = "[package]
+name = \"aoc\"
+
+[dependencies]
+aoc-input.workspace = true
+y2015-day-01 = { path = \"2015/day-01\" }
+y2023-day-01 = { path = \"2023/day-01\" }
+
+[workspace]
+members = [\"20*/day-*\", \"crates/*\"]
+";
+
+    const SOLUTIONS: &str = "mod y2015;
+mod y2023;
+
+pub fn for_year(year: u16) -> Option<&'static [Puzzle]> {
+    match year {
+        2015 => Some(y2015::PUZZLES),
+        2023 => Some(y2023::PUZZLES),
+        _ => None,
+    }
+}
+";
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-new-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/solutions")).unwrap();
+        fs::write(root.join("Cargo.toml"), ROOT_MANIFEST).unwrap();
+        fs::write(root.join("src/solutions.rs"), SOLUTIONS).unwrap();
+        root
+    }
+
+    #[test]
+    fn new_day_and_rerun() {
+        let root = temp_root("rerun");
+        let changes = new_day(&root, 2024, 5, Some("1 2\n3 \"4\"")).unwrap();
+        assert!(changes
+            .iter()
+            .all(|change| matches!(change, Change::Created(_) | Change::Updated(_))));
+
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains(
+            "y2023-day-01 = { path = \"2023/day-01\" }\n\
+             y2024-day-05 = { path = \"2024/day-05\" }\n\n[workspace]"
+        ));
+        let solutions = fs::read_to_string(root.join("src/solutions.rs")).unwrap();
+        assert!(solutions.starts_with("mod y2015;\nmod y2023;\nmod y2024;\n"));
+        assert!(solutions.contains(
+            "        2023 => Some(y2023::PUZZLES),\n        \
+             2024 => Some(y2024::PUZZLES),\n        _ => None,"
+        ));
+        let lib = fs::read_to_string(root.join("2024/day-05/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day {"));
+        assert!(lib.contains("aoc_solution::examples!();"));
+        assert_eq!(
+            fs::read_to_string(root.join("2024/day-05/examples/example.txt")).unwrap(),
+            "part1: ?\npart2: ?\n\n1 2\n3 \"4\"\n"
+        );
+        assert!(root.join("2024/day-05/build.rs").exists());
+        assert!(!root.join("2024/day-05/src/bin").exists());
+
+        // an earlier day slots in before it, and nothing is overwritten
+        fs::write(root.join("2024/day-05/src/lib.rs"), "my work").unwrap();
+        new_day(&root, 2024, 3, None).unwrap();
+        let changes = new_day(&root, 2024, 5, None).unwrap();
+        assert!(changes
+            .iter()
+            .all(|change| matches!(change, Change::Kept(_))));
+        assert_eq!(
+            fs::read_to_string(root.join("2024/day-05/src/lib.rs")).unwrap(),
+            "my work"
+        );
+        let year = fs::read_to_string(root.join("src/solutions/y2024.rs")).unwrap();
+        assert_eq!(year.matches("Puzzle {").count(), 2);
+        assert!(year.contains("solve: solve::<y2024_day_05::Day>,"));
+        assert!(year.find("y2024_day_03").unwrap() < year.find("y2024_day_05").unwrap());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml"))
+                .unwrap()
+                .matches("y2024")
+                .count(),
+            2
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn example_from_html() {
+        let html = "<article><p>For example:</p>\n\
+                    <pre><code>seeds: 79 14\n&lt;x=1&gt; <em>a&amp;b</em>\n</code></pre>\n\
+                    <pre><code>later</code></pre></article>";
+        assert_eq!(
+            extract_example(html),
+            Some("seeds: 79 14\n<x=1> a&b".to_string())
+        );
+        assert_eq!(extract_example("<p>no code here</p>"), None);
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+mod y2015;
+mod y2023;
+
+// one day, parsing the input once and answering the wanted parts from that
+pub struct Puzzle {
+    pub day: u8,
+    // the last day of a year only has a first part
+    pub parts: u8,
+    pub solve: fn(&str, &[u8]) -> Result<Solved, Failure>,
+}
+
+// why a day or part has no answer: no input to run on, an input that doesn't
+// suit it, or a panic the checks missed, which the panic hook has already reported
+#[derive(Debug)]
+pub enum Failure {
+    Missing(String),
+    Invalid(ParseError),
+    Panicked,
+}
+
+impl Failure {
+    // what to tell the user on top of the one-line summary
+    pub fn diagnostic(&self, input: &str) -> Option<String> {
+        match self {
+            Failure::Missing(error) => Some(error.clone()),
+            Failure::Invalid(error) => Some(error.diagnostic(input)),
+            Failure::Panicked => None,
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Missing(_) => write!(f, "no input"),
+            Failure::Invalid(_) => write!(f, "invalid input"),
+            Failure::Panicked => write!(f, "panicked"),
+        }
+    }
+}
+
+// so one day's panic doesn't take the other days down with it
+fn caught<T>(f: impl FnOnce() -> Result<T, ParseError>) -> Result<T, Failure> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(Failure::Invalid),
+        Err(_) => Err(Failure::Panicked),
+    }
+}
+
+// how long parsing took, then each part's answer (or why the input doesn't
+// suit that part) and how long it took
+pub struct Solved {
+    pub parse: Duration,
+    pub answers: Vec<(u8, Result<String, Failure>, Duration)>,
+}
+
+pub fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Solved, Failure>
+where
+    for<'a> S::Parsed<'a>: Sync,
+{
+    let now = Instant::now();
+    let parsed = caught(|| S::parse(input))?;
+    let parse = now.elapsed();
+    let answer = |part| {
+        let now = Instant::now();
+        let answer = caught(|| {
+            S::check(input, &parsed, part)?;
+            Ok(match part {
+                1 => S::part1(&parsed).to_string(),
+                _ => S::part2(&parsed).to_string(),
+            })
+        });
+        (part, answer, now.elapsed())
+    };
+    // both parts share the parse, but nothing else
+    let answers = match *parts {
+        [first, second] => {
+            let (first, second) = rayon::join(|| answer(first), || answer(second));
+            vec![first, second]
+        }
+        _ => parts.iter().map(|&part| answer(part)).collect(),
+    };
+    Ok(Solved { parse, answers })
+}
+
+pub fn for_year(year: u16) -> Option<&'static [Puzzle]> {
+    match year {
+        2015 => Some(y2015::PUZZLES),
+        2023 => Some(y2023::PUZZLES),
+        _ => None,
+    }
+}
@@ -0,0 +1,94 @@
+use super::{solve, Puzzle};
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle {
+        day: 1,
+        parts: 2,
+        solve: solve::<y2015_day_01::Day>,
+    },
+    Puzzle {
+        day: 2,
+        parts: 2,
+        solve: solve::<y2015_day_02::Day>,
+    },
+    Puzzle {
+        day: 3,
+        parts: 2,
+        solve: solve::<y2015_day_03::Day>,
+    },
+    Puzzle {
+        day: 4,
+        parts: 2,
+        solve: solve::<y2015_day_04::Day>,
+    },
+    Puzzle {
+        day: 5,
+        parts: 2,
+        solve: solve::<y2015_day_05::Day>,
+    },
+    Puzzle {
+        day: 6,
+        parts: 2,
+        solve: solve::<y2015_day_06::Day>,
+    },
+    Puzzle {
+        day: 7,
+        parts: 2,
+        solve: solve::<y2015_day_07::Day>,
+    },
+    Puzzle {
+        day: 8,
+        parts: 2,
+        solve: solve::<y2015_day_08::Day>,
+    },
+    Puzzle {
+        day: 9,
+        parts: 2,
+        solve: solve::<y2015_day_09::Day>,
+    },
+    Puzzle {
+        day: 10,
+        parts: 2,
+        solve: solve::<y2015_day_10::Day>,
+    },
+    Puzzle {
+        day: 11,
+        parts: 2,
+        solve: solve::<y2015_day_11::Day>,
+    },
+    Puzzle {
+        day: 12,
+        parts: 2,
+        solve: solve::<y2015_day_12::Day>,
+    },
+    Puzzle {
+        day: 13,
+        parts: 2,
+        solve: solve::<y2015_day_13::Day>,
+    },
+    Puzzle {
+        day: 14,
+        parts: 2,
+        solve: solve::<y2015_day_14::Day>,
+    },
+    Puzzle {
+        day: 15,
+        parts: 2,
+        solve: solve::<y2015_day_15::Day>,
+    },
+    Puzzle {
+        day: 16,
+        parts: 2,
+        solve: solve::<y2015_day_16::Day>,
+    },
+    Puzzle {
+        day: 17,
+        parts: 2,
+        solve: solve::<y2015_day_17::Day>,
+    },
+    Puzzle {
+        day: 18,
+        parts: 2,
+        solve: solve::<y2015_day_18::Day>,
+    },
+];
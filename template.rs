@@ -1,25 +1,26 @@
-fn main() {
-    let input = include_str!("../../input.txt");
-    let answer = get_answer(input);
-    println!("answer {answer}");
-}
+use aoc_parse::ParseError;
+use aoc_solution::Solution;
+use std::fmt::Display;
 
-fn parse_input(input: &str) -> bool {
-    true
-}
+pub struct Day;
 
-fn get_answer(input: &str) -> usize {
-    let data = parse_input(input);
-    1
-}
+impl Solution for Day {
+    type Parsed<'a> = Vec<&'a str>;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        Ok(input.lines().collect())
+    }
 
-    #[test]
-    fn example() {
-        let input = "";
-        assert_eq!(get_answer(input), 42);
+    fn part1(lines: &Self::Parsed<'_>) -> impl Display {
+        lines.len()
     }
+
+    fn part2(lines: &Self::Parsed<'_>) -> impl Display {
+        lines.len()
+    }
+}
+
+#[cfg(test)]
+mod examples {
+    aoc_solution::examples!();
 }